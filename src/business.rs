@@ -0,0 +1,238 @@
+use crate::Date;
+use crate::error::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// How a date that is not a business day is moved onto one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Roll {
+    /// the next business day
+    Following,
+    /// the previous business day
+    Preceding,
+    /// the next business day, unless that is in the next month, then the previous business day
+    ModifiedFollowing,
+}
+
+/// Business-day calendar, a weekend definition plus a set of holidays.
+///
+/// Load from text using the `FromStr` impl, one holiday per line,
+/// an optional `weekend=` line (1 = Monday ... 7 = Sunday) and `#` comments:
+/// ```rust
+/// use std::str::FromStr;
+/// let cal = fastdate::BusinessCalendar::from_str("
+/// weekend=6,7
+/// 2022-12-26
+/// ").unwrap();
+/// let d = fastdate::Date::from_str("2022-12-23").unwrap();
+/// assert_eq!(cal.add_business_days(&d, 2).to_string(), "2022-12-28");
+/// ```
+/// Format using the `Display` trait, serde uses the same text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BusinessCalendar {
+    /// index 1...7 is true when that week_day is weekend
+    weekend: [bool; 8],
+    holidays: HashSet<Date>,
+}
+
+impl Default for BusinessCalendar {
+    /// Saturday and Sunday weekend, no holidays
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BusinessCalendar {
+    /// Saturday and Sunday weekend, no holidays
+    pub fn new() -> Self {
+        Self {
+            weekend: [false, false, false, false, false, false, true, true],
+            holidays: HashSet::new(),
+        }
+    }
+
+    /// set weekend days, 1(Monday)...7(Sunday),
+    /// an error for a day out of range or a weekend of the whole week
+    pub fn set_weekend(mut self, days: &[u8]) -> Result<Self, Error> {
+        let mut weekend = [false; 8];
+        for day in days {
+            if !(1..=7).contains(day) {
+                return Err(Error::from(format!("InvalidWeekDay '{}'", day)));
+            }
+            weekend[*day as usize] = true;
+        }
+        if weekend[1..].iter().all(|v| *v) {
+            return Err(Error::from("WeekendIsWholeWeek"));
+        }
+        self.weekend = weekend;
+        Ok(self)
+    }
+
+    /// add one holiday
+    pub fn add_holiday(mut self, date: Date) -> Self {
+        self.holidays.insert(date);
+        self
+    }
+
+    /// add holidays
+    pub fn add_holidays<I: IntoIterator<Item = Date>>(mut self, dates: I) -> Self {
+        self.holidays.extend(dates);
+        self
+    }
+
+    /// weekend days, 1(Monday)...7(Sunday)
+    pub fn weekend(&self) -> Vec<u8> {
+        (1..=7).filter(|d| self.weekend[*d as usize]).collect()
+    }
+
+    /// holidays, sorted
+    pub fn holidays(&self) -> Vec<Date> {
+        let mut v: Vec<Date> = self.holidays.iter().cloned().collect();
+        v.sort_by_key(|d| d.days_since_epoch());
+        v
+    }
+
+    /// is week_day a weekend day?
+    pub fn is_weekend(&self, date: &Date) -> bool {
+        self.weekend[date.week_day() as usize]
+    }
+
+    /// is date a holiday?
+    pub fn is_holiday(&self, date: &Date) -> bool {
+        self.holidays.contains(date)
+    }
+
+    /// neither weekend nor holiday
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// add (or sub, when negative) business days, "T+2" is `add_business_days(&t, 2)`.
+    /// `days == 0` returns date unchanged, even if it is not a business day.
+    pub fn add_business_days(&self, date: &Date, days: i64) -> Date {
+        let step = if days >= 0 { 1 } else { -1 };
+        let mut left = days.abs();
+        let mut date = date.clone();
+        while left > 0 {
+            date = date.add_days(step);
+            if self.is_business_day(&date) {
+                left -= 1;
+            }
+        }
+        date
+    }
+
+    /// number of business days in `[start, end)`, negative when end is before start
+    pub fn business_days_between(&self, start: &Date, end: &Date) -> i64 {
+        let (from, to) = (start.days_since_epoch(), end.days_since_epoch());
+        if to < from {
+            return -self.business_days_between(end, start);
+        }
+        let weeks = (to - from) / 7;
+        let weekend_days = self.weekend.iter().filter(|v| **v).count() as i64;
+        let mut count = weeks * (7 - weekend_days);
+        let mut day = Date::from_days_since_epoch(from + weeks * 7);
+        for _ in (from + weeks * 7)..to {
+            if !self.is_weekend(&day) {
+                count += 1;
+            }
+            day = day.add_days(1);
+        }
+        for holiday in &self.holidays {
+            let v = holiday.days_since_epoch();
+            if v >= from && v < to && !self.is_weekend(holiday) {
+                count -= 1;
+            }
+        }
+        count
+    }
+
+    /// move date onto a business day, business days are returned unchanged
+    pub fn roll(&self, date: &Date, roll: Roll) -> Date {
+        match roll {
+            Roll::Following => self.step_to_business_day(date, 1),
+            Roll::Preceding => self.step_to_business_day(date, -1),
+            Roll::ModifiedFollowing => {
+                let v = self.step_to_business_day(date, 1);
+                if v.mon != date.mon {
+                    self.step_to_business_day(date, -1)
+                } else {
+                    v
+                }
+            }
+        }
+    }
+
+    fn step_to_business_day(&self, date: &Date, step: i64) -> Date {
+        let mut date = date.clone();
+        while !self.is_business_day(&date) {
+            date = date.add_days(step);
+        }
+        date
+    }
+}
+
+impl FromStr for BusinessCalendar {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cal = BusinessCalendar::new();
+        for line in s.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(days) = line.strip_prefix("weekend=") {
+                let mut weekend = Vec::new();
+                for day in days.split(',').map(str::trim).filter(|v| !v.is_empty()) {
+                    weekend.push(
+                        day.parse::<u8>()
+                            .map_err(|_| Error::from(format!("InvalidWeekDay '{}'", day)))?,
+                    );
+                }
+                cal = cal.set_weekend(&weekend)?;
+            } else {
+                cal = cal.add_holiday(Date::from_str(line)?);
+            }
+        }
+        Ok(cal)
+    }
+}
+
+impl Display for BusinessCalendar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("weekend=")?;
+        for (i, day) in self.weekend().iter().enumerate() {
+            if i != 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}", day)?;
+        }
+        for holiday in self.holidays() {
+            write!(f, "\n{}", holiday)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for BusinessCalendar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BusinessCalendar {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        BusinessCalendar::from_str(&String::deserialize(deserializer)?)
+            .map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
@@ -0,0 +1,428 @@
+use crate::date::days_in_month;
+use crate::error::Error;
+use crate::{Date, DateTime, Time};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// 400 gregorian years, after which every calendar pattern (week days included) repeats
+const DAYS_PER_CYCLE: i64 = 146_097;
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+const WEEK_DAYS: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// A cron expression, "*/15 9-17 * * MON-FRI".
+///
+/// Five fields are minute, hour, day of month, month and day of week,
+/// six fields add the second in front. Besides numbers, `*`, ranges `a-b`, steps `/n`
+/// and lists `a,b`, it supports
+/// * month names JAN...DEC and week day names SUN...SAT, week day 0 and 7 are Sunday,
+///   so `SAT-SUN` is Saturday and Sunday
+/// * `?` for an unrestricted day of month or day of week
+/// * `L` the last day of the month, `L-3` three days before it,
+///   `15W` the week day nearest the 15th and `LW` the last week day of the month
+/// * `5L` the last Friday of the month and `5#3` the third Friday of the month
+/// * the macros `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight` and `@hourly`
+///
+/// When both the day of month and the day of week are restricted, a day matching
+/// either fires, like the classic cron. When either starts with `*` (`*/2` too) or is `?`,
+/// a day must match both, so `0 0 */10 * MON` fires on Mondays the 1st, 11th, 21st or 31st.
+///
+/// The times are evaluated in the fixed offset of the `DateTime` given,
+/// there are no named zones, so there are no DST gaps or repeated hours.
+/// ```rust
+/// use std::str::FromStr;
+/// use fastdate::{Cron, DateTime};
+/// let cron = Cron::from_str("0 9 * * MON-FRI").unwrap();
+/// let now = DateTime::from_str("2022-12-16T10:00:00+08:00").unwrap();
+/// assert_eq!(cron.next_after(&now).unwrap().to_string(), "2022-12-19T09:00:00+08:00");
+/// assert_eq!(cron.prev_before(&now).unwrap().to_string(), "2022-12-16T09:00:00+08:00");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Cron {
+    /// the expression, as parsed
+    source: String,
+    /// bit n for second n
+    second: u64,
+    /// bit n for minute n
+    minute: u64,
+    /// bit n for hour n
+    hour: u64,
+    /// bit n for day n of the month
+    month_day: u64,
+    /// bit n for month n
+    month: u64,
+    /// bit n for week day n, 1(Monday)...7(Sunday)
+    week_day: u64,
+    /// `L` is 0, `L-3` is 3
+    last_day: Vec<u8>,
+    /// `15W`
+    nearest_week_day: Vec<u8>,
+    /// `LW`
+    last_week_day: bool,
+    /// `5#3` is (5, 3)
+    nth_week_day: Vec<(u8, u8)>,
+    /// `5L`
+    last_of_week_day: Vec<u8>,
+    /// day of month starts with `*` or is `?`
+    any_month_day: bool,
+    /// day of week starts with `*` or is `?`
+    any_week_day: bool,
+}
+
+impl Cron {
+    /// the first fire time after `datetime`, at its offset,
+    /// `None` when the expression never fires or the time is out of range
+    pub fn next_after(&self, datetime: &DateTime) -> Option<DateTime> {
+        let local = datetime.unix_timestamp() + datetime.offset() as i64 + 1;
+        self.find(local, datetime.offset(), true)
+    }
+
+    /// the last fire time before `datetime`, at its offset,
+    /// `None` when the expression never fires or the time is out of range
+    pub fn prev_before(&self, datetime: &DateTime) -> Option<DateTime> {
+        let mut local = datetime.unix_timestamp() + datetime.offset() as i64;
+        if datetime.nano() == 0 {
+            local -= 1;
+        }
+        self.find(local, datetime.offset(), false)
+    }
+
+    /// the fire times after `datetime`, in order
+    pub fn upcoming(&self, datetime: &DateTime) -> CronIter {
+        CronIter {
+            cron: self.clone(),
+            last: Some(datetime.clone()),
+        }
+    }
+
+    /// search from local seconds since 1970-01-01, inclusive, forward or backward
+    fn find(&self, local: i64, offset: i32, forward: bool) -> Option<DateTime> {
+        let start_day = local.div_euclid(86400);
+        let start_secs = local.rem_euclid(86400) as u32;
+        let (min, max) = (Date::MIN.days_since_epoch(), Date::MAX.days_since_epoch());
+        let mut day = start_day;
+        while (day - start_day).abs() <= DAYS_PER_CYCLE && (min..=max).contains(&day) {
+            let date = Date::from_days_since_epoch(day);
+            if !has(self.month, date.mon) {
+                // jump to the first (or last) day of the next (or previous) month
+                day += if forward {
+                    (days_in_month(date.year, date.mon) - date.day + 1) as i64
+                } else {
+                    -(date.day as i64)
+                };
+                continue;
+            }
+            if self.matches_date(&date) {
+                let from = match (day == start_day, forward) {
+                    (true, _) => start_secs,
+                    (false, true) => 0,
+                    (false, false) => 86399,
+                };
+                if let Some(secs) = self.find_time(from, forward) {
+                    let time = Time::from_nanos_of_day(secs as u64 * 1_000_000_000);
+                    return DateTime::new(date, time, offset).ok();
+                }
+            }
+            day += if forward { 1 } else { -1 };
+        }
+        None
+    }
+
+    /// the first (or last) matching second of the day from `from`, inclusive
+    fn find_time(&self, from: u32, forward: bool) -> Option<u32> {
+        let (h0, m0, s0) = (from / 3600, from / 60 % 60, from % 60);
+        let bound = |bits: u64, at: u32, first: bool| -> Option<u32> {
+            match (first, forward) {
+                (true, true) => next_bit(bits, at),
+                (true, false) => prev_bit(bits, at),
+                (false, true) => next_bit(bits, 0),
+                (false, false) => prev_bit(bits, 59),
+            }
+        };
+        let step = |v: u32| -> Option<u32> {
+            if forward {
+                Some(v + 1)
+            } else {
+                v.checked_sub(1)
+            }
+        };
+        let mut hour = bound(self.hour, h0, true);
+        while let Some(h) = hour {
+            let mut minute = bound(self.minute, m0, h == h0);
+            while let Some(m) = minute {
+                if let Some(s) = bound(self.second, s0, h == h0 && m == m0) {
+                    return Some(h * 3600 + m * 60 + s);
+                }
+                minute = step(m).and_then(|m| bound(self.minute, m, true));
+            }
+            hour = step(h).and_then(|h| bound(self.hour, h, true));
+        }
+        None
+    }
+
+    /// does the day of month and day of week match?
+    fn matches_date(&self, date: &Date) -> bool {
+        if self.any_month_day || self.any_week_day {
+            self.matches_month_day(date) && self.matches_week_day(date)
+        } else {
+            self.matches_month_day(date) || self.matches_week_day(date)
+        }
+    }
+
+    fn matches_month_day(&self, date: &Date) -> bool {
+        let dim = days_in_month(date.year, date.mon);
+        has(self.month_day, date.day)
+            || self
+                .last_day
+                .iter()
+                .any(|v| dim.checked_sub(*v) == Some(date.day))
+            || self
+                .nearest_week_day
+                .iter()
+                .any(|v| *v <= dim && nearest_week_day(date, *v) == date.day)
+            || (self.last_week_day && nearest_week_day(date, dim) == date.day)
+    }
+
+    fn matches_week_day(&self, date: &Date) -> bool {
+        let dim = days_in_month(date.year, date.mon);
+        let week_day = date.week_day();
+        has(self.week_day, week_day)
+            || self
+                .nth_week_day
+                .iter()
+                .any(|(d, n)| *d == week_day && (date.day - 1) / 7 + 1 == *n)
+            || self
+                .last_of_week_day
+                .iter()
+                .any(|d| *d == week_day && date.day + 7 > dim)
+    }
+}
+
+/// the week day nearest to `day` of the month of date, without leaving the month
+fn nearest_week_day(date: &Date, day: u8) -> u8 {
+    let dim = days_in_month(date.year, date.mon);
+    let week_day = Date {
+        day,
+        mon: date.mon,
+        year: date.year,
+    }
+    .week_day();
+    match week_day {
+        6 if day == 1 => 3,
+        6 => day - 1,
+        7 if day == dim => day - 2,
+        7 => day + 1,
+        _ => day,
+    }
+}
+
+fn has(bits: u64, v: u8) -> bool {
+    bits & (1 << v) != 0
+}
+
+/// the lowest set bit at or above `from`
+fn next_bit(bits: u64, from: u32) -> Option<u32> {
+    if from > 63 {
+        return None;
+    }
+    let v = bits >> from;
+    (v != 0).then(|| from + v.trailing_zeros())
+}
+
+/// the highest set bit at or below `from`
+fn prev_bit(bits: u64, from: u32) -> Option<u32> {
+    let v = bits & (u64::MAX >> (63 - from.min(63)));
+    (v != 0).then(|| 63 - v.leading_zeros())
+}
+
+/// parse a number or a name of `names` (numbered from `first`)
+fn parse_value(s: &str, names: &[&str], first: u8, field: &str) -> Result<u8, Error> {
+    if let Some(i) = names.iter().position(|v| v.eq_ignore_ascii_case(s)) {
+        return Ok(i as u8 + first);
+    }
+    s.parse::<u8>()
+        .map_err(|_| Error::from(format!("InvalidCronField '{}'", field)))
+}
+
+/// parse `*`, `a`, `a-b`, with an optional `/n`, into bits of min...max
+fn parse_range(s: &str, min: u8, max: u8, names: &[&str], first: u8) -> Result<u64, Error> {
+    let err = || Error::from(format!("InvalidCronField '{}'", s));
+    let (range, step) = match s.split_once('/') {
+        Some((range, step)) => (range, Some(step.parse::<u8>().map_err(|_| err())?)),
+        None => (s, None),
+    };
+    let (start, end) = match range {
+        "*" | "?" => (min, max),
+        _ => match range.split_once('-') {
+            Some((a, b)) => (
+                parse_value(a, names, first, s)?,
+                parse_value(b, names, first, s)?,
+            ),
+            None => {
+                let v = parse_value(range, names, first, s)?;
+                (v, if step.is_some() { max } else { v })
+            }
+        },
+    };
+    if start < min || end > max || start > end || step == Some(0) {
+        return Err(err());
+    }
+    let mut bits = 0;
+    for v in (start..=end).step_by(step.unwrap_or(1) as usize) {
+        bits |= 1 << v;
+    }
+    Ok(bits)
+}
+
+fn parse_field(s: &str, min: u8, max: u8, names: &[&str], first: u8) -> Result<u64, Error> {
+    let mut bits = 0;
+    for part in s.split(',') {
+        bits |= parse_range(part, min, max, names, first)?;
+    }
+    Ok(bits)
+}
+
+impl FromStr for Cron {
+    type Err = Error;
+
+    /// "*/15 9-17 * * MON-FRI", "0 0 12 L * ?" or "@daily"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let source = s.split_whitespace().collect::<Vec<_>>().join(" ");
+        let expanded = match source.to_ascii_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 0 1 1 *",
+            "@monthly" => "0 0 0 1 * *",
+            "@weekly" => "0 0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 0 * * *",
+            "@hourly" => "0 0 * * * *",
+            v if v.starts_with('@') => {
+                return Err(Error::from(format!("InvalidCronMacro '{}'", source)));
+            }
+            _ => source.as_str(),
+        };
+        let mut fields: Vec<&str> = expanded.split(' ').collect();
+        match fields.len() {
+            5 => fields.insert(0, "0"),
+            6 => {}
+            _ => return Err(Error::from(format!("InvalidCronFieldCount '{}'", source))),
+        }
+        let mut cron = Cron {
+            second: parse_field(fields[0], 0, 59, &[], 0)?,
+            minute: parse_field(fields[1], 0, 59, &[], 0)?,
+            hour: parse_field(fields[2], 0, 23, &[], 0)?,
+            month_day: 0,
+            month: parse_field(fields[4], 1, 12, &MONTHS, 1)?,
+            week_day: 0,
+            last_day: vec![],
+            nearest_week_day: vec![],
+            last_week_day: false,
+            nth_week_day: vec![],
+            last_of_week_day: vec![],
+            any_month_day: fields[3].starts_with('*') || fields[3] == "?",
+            any_week_day: fields[5].starts_with('*') || fields[5] == "?",
+            source: source.clone(),
+        };
+        for part in fields[3].split(',') {
+            let err = || Error::from(format!("InvalidCronField '{}'", part));
+            let upper = part.to_ascii_uppercase();
+            if upper == "L" {
+                cron.last_day.push(0);
+            } else if upper == "LW" {
+                cron.last_week_day = true;
+            } else if let Some(v) = upper.strip_prefix("L-") {
+                cron.last_day
+                    .push(v.parse::<u8>().ok().filter(|v| *v < 31).ok_or_else(err)?);
+            } else if let Some(v) = upper.strip_suffix('W') {
+                cron.nearest_week_day.push(
+                    v.parse::<u8>()
+                        .ok()
+                        .filter(|v| (1..=31).contains(v))
+                        .ok_or_else(err)?,
+                );
+            } else {
+                cron.month_day |= parse_range(part, 1, 31, &[], 1)?;
+            }
+        }
+        for part in fields[5].split(',') {
+            let err = || Error::from(format!("InvalidCronField '{}'", part));
+            // week day 0...7 with 0 and 7 for Sunday, as 1(Monday)...7(Sunday)
+            let week_day = |v: &str| -> Result<u8, Error> {
+                match parse_value(v, &WEEK_DAYS, 0, part)? {
+                    0 => Ok(7),
+                    v @ 1..=7 => Ok(v),
+                    _ => Err(err()),
+                }
+            };
+            if let Some((d, n)) = part.split_once('#') {
+                let n = n.parse::<u8>().ok().filter(|v| (1..=5).contains(v));
+                cron.nth_week_day.push((week_day(d)?, n.ok_or_else(err)?));
+            } else if let Some(d) = part.strip_suffix(['L', 'l'])
+                && !d.is_empty()
+            {
+                cron.last_of_week_day.push(week_day(d)?);
+            } else {
+                // Sunday at the end of a range is 7, `SAT-SUN`
+                let part = match part.split_once('-') {
+                    Some((a, b)) => {
+                        let end = b.split('/').next().unwrap_or(b);
+                        match (week_day(a), parse_value(end, &WEEK_DAYS, 0, part)) {
+                            (Ok(1..=6), Ok(0)) => format!("{}-7{}", a, &b[end.len()..]),
+                            _ => part.to_string(),
+                        }
+                    }
+                    None => part.to_string(),
+                };
+                let bits = parse_range(&part, 0, 7, &WEEK_DAYS, 0)?;
+                cron.week_day |= (bits & !1) | ((bits & 1) << 7);
+            }
+        }
+        Ok(cron)
+    }
+}
+
+impl Display for Cron {
+    /// the expression as parsed, with whitespace collapsed
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl Serialize for Cron {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cron {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Cron::from_str(&String::deserialize(deserializer)?)
+            .map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
+
+/// The upcoming fire times of a `Cron`, see `Cron::upcoming`
+#[derive(Clone, Debug)]
+pub struct CronIter {
+    cron: Cron,
+    last: Option<DateTime>,
+}
+
+impl Iterator for CronIter {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.cron.next_after(self.last.as_ref()?);
+        self.last = next.clone();
+        next
+    }
+}
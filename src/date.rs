@@ -1,5 +1,7 @@
 use crate::{DateTime, get_digit_unchecked};
+use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp;
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
@@ -14,87 +16,99 @@ use crate::error::Error;
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Date {
     /// 1...31
-    pub day: u8,
+    pub(crate) day: u8,
     /// 1...12
-    pub mon: u8,
-    /// 0000...9999
-    pub year: i32,
+    pub(crate) mon: u8,
+    /// -999999...999999, see `Date::MIN` and `Date::MAX`
+    pub(crate) year: i32,
 }
 
 impl Date {
-    /// Parse a date from bytes, no check is performed for extract characters at the end of the string
-    pub(crate) fn parse_bytes_partial(bytes: &[u8]) -> Result<Self, Error> {
-        if bytes.len() < 10 {
-            return Err(Error::E("TooShort".to_string()));
-        }
-        let year: u16;
-        let month: u8;
-        let day: u8;
-        unsafe {
-            let y1 = get_digit_unchecked!(bytes, 0, "InvalidCharYear") as u16;
-            let y2 = get_digit_unchecked!(bytes, 1, "InvalidCharYear") as u16;
-            let y3 = get_digit_unchecked!(bytes, 2, "InvalidCharYear") as u16;
-            let y4 = get_digit_unchecked!(bytes, 3, "InvalidCharYear") as u16;
-            year = y1 * 1000 + y2 * 100 + y3 * 10 + y4;
+    /// the earliest supported date, "-999999-01-01"
+    pub const MIN: Date = Date {
+        day: 1,
+        mon: 1,
+        year: -999_999,
+    };
 
-            let m1 = get_digit_unchecked!(bytes, 5, "InvalidCharMonth");
-            let m2 = get_digit_unchecked!(bytes, 6, "InvalidCharMonth");
-            month = m1 * 10 + m2;
+    /// the latest supported date, "+999999-12-31"
+    pub const MAX: Date = Date {
+        day: 31,
+        mon: 12,
+        year: 999_999,
+    };
 
-            let d1 = get_digit_unchecked!(bytes, 8, "InvalidCharDay");
-            let d2 = get_digit_unchecked!(bytes, 9, "InvalidCharDay");
-            day = d1 * 10 + d2;
-        }
+    /// Parse a date from bytes, no check is performed for extract characters at the end of the string.
+    /// years out of 0000...9999 use the ISO 8601 expanded representation, "+012345-01-01" or "-0044-03-15"
+    pub(crate) fn parse_bytes_partial(bytes: &[u8]) -> Result<Self, Error> {
+        let (year, mon, day) = parse_ymd(bytes)?;
+        let date = Self { day, mon, year };
+        date.check()?;
+        Ok(date)
+    }
 
-        // calculate the maximum number of days in the month, accounting for leap years in the
-        // gregorian calendar
-        let max_days = match month {
-            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-            4 | 6 | 9 | 11 => 30,
-            2 => {
-                if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
-                    29
-                } else {
-                    28
-                }
-            }
-            _ => return Err(Error::E("OutOfRangeMonth".to_string())),
-        };
+    /// new a date, checks that year/mon/day is a valid calendar date
+    /// ```rust
+    /// assert!(fastdate::Date::new(2024, 2, 29).is_ok());
+    /// assert!(fastdate::Date::new(2023, 2, 29).is_err());
+    /// ```
+    pub fn new(year: i32, mon: u8, day: u8) -> Result<Self, Error> {
+        let date = Self { day, mon, year };
+        date.check()?;
+        Ok(date)
+    }
+
+    /// is year/mon/day a valid calendar date?
+    /// always true, the fields are private and every constructor checks them
+    pub fn is_valid(&self) -> bool {
+        self.check().is_ok()
+    }
 
-        if day < 1 || day > max_days {
+    pub(crate) fn check(&self) -> Result<(), Error> {
+        if !(Self::MIN.year..=Self::MAX.year).contains(&self.year) {
+            return Err(Error::E("OutOfRangeYear".to_string()));
+        }
+        if !(1..=12).contains(&self.mon) {
+            return Err(Error::E("OutOfRangeMonth".to_string()));
+        }
+        if self.day < 1 || self.day > days_in_month(self.year, self.mon) {
             return Err(Error::E("OutOfRangeDay".to_string()));
         }
-
-        Ok(Self {
-            day,
-            mon: month,
-            year: year as i32,
-        })
+        Ok(())
     }
 
-    /// 1...31
-    pub fn set_day(mut self, arg: u8) -> Self {
-        if arg == 0 || arg > 31 {
-            return self;
-        }
+    /// set day, or an error when the date would not be valid
+    pub fn with_day(mut self, arg: u8) -> Result<Self, Error> {
         self.day = arg;
-        self
+        self.check()?;
+        Ok(self)
     }
-    /// 1...12
-    pub fn set_mon(mut self, arg: u8) -> Self {
-        if arg == 0 || arg > 12 {
-            return self;
-        }
+
+    /// set mon, or an error when the date would not be valid
+    pub fn with_mon(mut self, arg: u8) -> Result<Self, Error> {
         self.mon = arg;
-        self
+        self.check()?;
+        Ok(self)
     }
-    /// 0000...9999
-    pub fn set_year(mut self, arg: i32) -> Self {
-        if !(0..=9999).contains(&arg) {
-            return self;
-        }
+
+    /// set year, or an error when the date would not be valid
+    pub fn with_year(mut self, arg: i32) -> Result<Self, Error> {
         self.year = arg;
-        self
+        self.check()?;
+        Ok(self)
+    }
+
+    /// 1...31, ignored when the date would not be valid
+    pub fn set_day(self, arg: u8) -> Self {
+        self.clone().with_day(arg).unwrap_or(self)
+    }
+    /// 1...12, ignored when the date would not be valid
+    pub fn set_mon(self, arg: u8) -> Self {
+        self.clone().with_mon(arg).unwrap_or(self)
+    }
+    /// -999999...999999, ignored when the date would not be valid
+    pub fn set_year(self, arg: i32) -> Self {
+        self.clone().with_year(arg).unwrap_or(self)
     }
 
     /// get day
@@ -112,21 +126,312 @@ impl Date {
         self.year
     }
 
-    /// display date and return len
+    /// display date and return len, "YYYY-MM-DD" or with an expanded year "+YYYYYY-MM-DD",
+    /// buf needs 10 bytes after start, 13 for an expanded year
     pub fn display_date(&self, start: usize, buf: &mut [u8]) -> usize {
-        buf[start] = b'0' + (self.year / 1000) as u8;
-        buf[start + 1] = b'0' + (self.year / 100 % 10) as u8;
-        buf[start + 2] = b'0' + (self.year / 10 % 10) as u8;
-        buf[start + 3] = b'0' + (self.year % 10) as u8;
+        let start = write_year_bytes(buf, start, self.year);
+        buf[start] = b'-';
+        buf[start + 1] = b'0' + (self.mon / 10);
+        buf[start + 2] = b'0' + (self.mon % 10);
+        buf[start + 3] = b'-';
+        buf[start + 4] = b'0' + (self.day / 10);
+        buf[start + 5] = b'0' + (self.day % 10);
+        start + 6
+    }
+
+    /// get week_day, 1(Monday)...7(Sunday), same as `DateTime::week_day`
+    pub fn week_day(&self) -> u8 {
+        ((self.days_since_epoch() + 3).rem_euclid(7) + 1) as u8
+    }
+
+    /// days since 1970-01-01
+    pub(crate) fn days_since_epoch(&self) -> i64 {
+        days_from_civil(self.year, self.mon, self.day)
+    }
+
+    /// date from days since 1970-01-01
+    pub(crate) fn from_days_since_epoch(days: i64) -> Self {
+        let (year, mon, day) = civil_from_days(days);
+        Self { day, mon, year }
+    }
+
+    /// add (or sub, when negative) whole days
+    pub(crate) fn add_days(&self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// date from days since 1970-01-01, or an error when out of `Date::MIN...Date::MAX`
+    pub(crate) fn try_from_days_since_epoch(days: i64) -> Result<Self, Error> {
+        if days < Self::MIN.days_since_epoch() || days > Self::MAX.days_since_epoch() {
+            return Err(Error::E("OutOfRangeDate".to_string()));
+        }
+        Ok(Self::from_days_since_epoch(days))
+    }
+
+    /// Julian Day Number, the julian day starting at noon (UTC) of this date
+    /// ```rust
+    /// let d = fastdate::Date::new(2022, 12, 13).unwrap();
+    /// assert_eq!(d.to_julian_day(), 2459927);
+    /// ```
+    pub fn to_julian_day(&self) -> i64 {
+        self.days_since_epoch() + UNIX_EPOCH_JULIAN_DAY
+    }
+
+    /// date from a Julian Day Number
+    pub fn from_julian_day(jdn: i64) -> Result<Self, Error> {
+        Self::try_from_days_since_epoch(jdn - UNIX_EPOCH_JULIAN_DAY)
+    }
+
+    /// Modified Julian Date, days since 1858-11-17
+    /// ```rust
+    /// let d = fastdate::Date::new(2022, 12, 13).unwrap();
+    /// assert_eq!(d.to_mjd(), 59926);
+    /// ```
+    pub fn to_mjd(&self) -> i64 {
+        self.days_since_epoch() + UNIX_EPOCH_MJD
+    }
+
+    /// date from a Modified Julian Date
+    pub fn from_mjd(mjd: i64) -> Result<Self, Error> {
+        Self::try_from_days_since_epoch(mjd - UNIX_EPOCH_MJD)
+    }
+
+    /// Rata Die, 0001-01-01 is day 1
+    /// ```rust
+    /// let d = fastdate::Date::new(1, 1, 1).unwrap();
+    /// assert_eq!(d.to_rata_die(), 1);
+    /// ```
+    pub fn to_rata_die(&self) -> i64 {
+        self.days_since_epoch() + UNIX_EPOCH_RATA_DIE
+    }
+
+    /// date from a Rata Die day number
+    pub fn from_rata_die(rd: i64) -> Result<Self, Error> {
+        Self::try_from_days_since_epoch(rd - UNIX_EPOCH_RATA_DIE)
+    }
+
+    /// the first day of the unit containing this date, saturating at `Date::MIN`
+    /// ```rust
+    /// use fastdate::{CalendarUnit, Date, WeekStart};
+    /// let d = Date::new(2022, 12, 13).unwrap();
+    /// assert_eq!(d.start_of(CalendarUnit::Week(WeekStart::Monday)).to_string(), "2022-12-12");
+    /// assert_eq!(d.start_of(CalendarUnit::Quarter).to_string(), "2022-10-01");
+    /// ```
+    pub fn start_of(&self, unit: CalendarUnit) -> Self {
+        let (year, mon) = (self.year, self.mon);
+        match unit {
+            CalendarUnit::Day => self.clone(),
+            CalendarUnit::Week(start) => {
+                let back = match start {
+                    WeekStart::Monday => self.week_day() - 1,
+                    WeekStart::Sunday => self.week_day() % 7,
+                };
+                Self::try_from_days_since_epoch(self.days_since_epoch() - back as i64)
+                    .unwrap_or(Self::MIN)
+            }
+            CalendarUnit::Month => Date { day: 1, mon, year },
+            CalendarUnit::Quarter => Date {
+                day: 1,
+                mon: (mon - 1) / 3 * 3 + 1,
+                year,
+            },
+            CalendarUnit::Year => Date {
+                day: 1,
+                mon: 1,
+                year,
+            },
+        }
+    }
+
+    /// the last day of the unit containing this date, saturating at `Date::MAX`
+    pub fn end_of(&self, unit: CalendarUnit) -> Self {
+        let year = self.year;
+        match unit {
+            CalendarUnit::Day => self.clone(),
+            CalendarUnit::Week(_) => {
+                Self::try_from_days_since_epoch(self.start_of(unit).days_since_epoch() + 6)
+                    .unwrap_or(Self::MAX)
+            }
+            CalendarUnit::Month => Date {
+                day: days_in_month(year, self.mon),
+                mon: self.mon,
+                year,
+            },
+            CalendarUnit::Quarter => {
+                let mon = (self.mon - 1) / 3 * 3 + 3;
+                Date {
+                    day: days_in_month(year, mon),
+                    mon,
+                    year,
+                }
+            }
+            CalendarUnit::Year => Date {
+                day: 31,
+                mon: 12,
+                year,
+            },
+        }
+    }
+
+    /// the first day after the unit containing this date, for half-open ranges,
+    /// an error past `Date::MAX`
+    pub fn end_of_exclusive(&self, unit: CalendarUnit) -> Result<Self, Error> {
+        Self::try_from_days_since_epoch(self.end_of(unit).days_since_epoch() + 1)
+    }
+}
 
-        buf[start + 5] = b'0' + (self.mon / 10);
-        buf[start + 6] = b'0' + (self.mon % 10);
+/// The first day of a week, see `CalendarUnit::Week`
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum WeekStart {
+    /// ISO 8601 weeks
+    #[default]
+    Monday,
+    Sunday,
+}
+
+/// The calendar units of `start_of` and `end_of`
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum CalendarUnit {
+    Day,
+    Week(WeekStart),
+    Month,
+    /// January, April, July and October start a quarter
+    Quarter,
+    Year,
+}
 
-        buf[start + 8] = b'0' + (self.day / 10);
-        buf[start + 9] = b'0' + (self.day % 10);
+/// Julian Day Number of 1970-01-01
+pub(crate) const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+/// Modified Julian Date of 1970-01-01
+pub(crate) const UNIX_EPOCH_MJD: i64 = 40_587;
+/// Rata Die of 1970-01-01
+pub(crate) const UNIX_EPOCH_RATA_DIE: i64 = 719_163;
 
-        start + 10
+/// read the year, mon and day digits of "YYYY-MM-DD" or an expanded year "+YYYYYY-MM-DD",
+/// without checking them against a calendar
+pub(crate) fn parse_ymd(bytes: &[u8]) -> Result<(i32, u8, u8), Error> {
+    let (mut year, start) = match bytes.first() {
+        Some(b'+' | b'-') => parse_expanded_year(bytes)?,
+        _ => (0, 4),
+    };
+    if bytes.len() < start + 6 {
+        return Err(Error::E("TooShort".to_string()));
     }
+    let month: u8;
+    let day: u8;
+    unsafe {
+        if start == 4 {
+            let y1 = get_digit_unchecked!(bytes, 0, "InvalidCharYear") as i32;
+            let y2 = get_digit_unchecked!(bytes, 1, "InvalidCharYear") as i32;
+            let y3 = get_digit_unchecked!(bytes, 2, "InvalidCharYear") as i32;
+            let y4 = get_digit_unchecked!(bytes, 3, "InvalidCharYear") as i32;
+            year = y1 * 1000 + y2 * 100 + y3 * 10 + y4;
+        }
+
+        let m1 = get_digit_unchecked!(bytes, start + 1, "InvalidCharMonth");
+        let m2 = get_digit_unchecked!(bytes, start + 2, "InvalidCharMonth");
+        month = m1 * 10 + m2;
+
+        let d1 = get_digit_unchecked!(bytes, start + 4, "InvalidCharDay");
+        let d2 = get_digit_unchecked!(bytes, start + 5, "InvalidCharDay");
+        day = d1 * 10 + d2;
+    }
+    Ok((year, month, day))
+}
+
+/// parse an ISO 8601 expanded year, a sign and 4...6 digits, return (year, len)
+pub(crate) fn parse_expanded_year(bytes: &[u8]) -> Result<(i32, usize), Error> {
+    let negative = match bytes.first() {
+        Some(b'+') => false,
+        Some(b'-') => true,
+        _ => return Err(Error::E("InvalidCharYear".to_string())),
+    };
+    let mut year: i32 = 0;
+    let mut len = 1;
+    while let Some(c) = bytes.get(len).filter(|c| c.is_ascii_digit()) {
+        if len > 6 {
+            return Err(Error::E("OutOfRangeYear".to_string()));
+        }
+        year = year * 10 + (c - b'0') as i32;
+        len += 1;
+    }
+    if len < 5 {
+        return Err(Error::E("InvalidCharYear".to_string()));
+    }
+    Ok((if negative { -year } else { year }, len))
+}
+
+/// write an ISO 8601 year, 4 digits for 0000...9999,
+/// else the expanded representation of a sign and 6 digits, "-000044" or "+012345"
+pub(crate) fn write_year<W: fmt::Write>(f: &mut W, year: i32) -> fmt::Result {
+    let mut buf = [0u8; 7];
+    let len = write_year_bytes(&mut buf, 0, year);
+    f.write_str(std::str::from_utf8(&buf[..len]).unwrap())
+}
+
+/// write the year like `write_year` into buf at start, return the end
+pub(crate) fn write_year_bytes(buf: &mut [u8], start: usize, year: i32) -> usize {
+    let (start, digits) = match year {
+        0..=9999 => (start, 4),
+        _ => {
+            buf[start] = if year < 0 { b'-' } else { b'+' };
+            (start + 1, 6)
+        }
+    };
+    let mut v = year.unsigned_abs();
+    for i in (start..start + digits).rev() {
+        buf[i] = b'0' + (v % 10) as u8;
+        v /= 10;
+    }
+    start + digits
+}
+
+/// number of days in the month, accounting for leap years in the gregorian calendar.
+/// returns 0 for a mon out of 1...12
+pub(crate) fn days_in_month(year: i32, mon: u8) -> u8 {
+    match mon {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => 0,
+    }
+}
+
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// days since 1970-01-01 of a proleptic gregorian date
+/// (http://howardhinnant.github.io/date_algorithms.html#days_from_civil)
+pub(crate) fn days_from_civil(year: i32, mon: u8, day: u8) -> i64 {
+    let y = year as i64 - if mon <= 2 { 1 } else { 0 };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = mon as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// proleptic gregorian (year, mon, day) of days since 1970-01-01
+/// (http://howardhinnant.github.io/date_algorithms.html#civil_from_days)
+pub(crate) fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let mon = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + if mon <= 2 { 1 } else { 0 };
+    (year as i32, mon, day)
 }
 
 impl FromStr for Date {
@@ -139,29 +444,92 @@ impl FromStr for Date {
 
 impl Display for Date {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let mut buf: [u8; 10] = *b"0000-00-00";
-        self.display_date(0, &mut buf);
-        f.write_str(std::str::from_utf8(&buf[..]).unwrap())
+        let mut buf = [0u8; 13];
+        let len = self.display_date(0, &mut buf);
+        f.write_str(std::str::from_utf8(&buf[..len]).unwrap())
+    }
+}
+
+impl Ord for Date {
+    fn cmp(&self, other: &Date) -> cmp::Ordering {
+        (self.year, self.mon, self.day).cmp(&(other.year, other.mon, other.day))
+    }
+}
+
+impl PartialOrd for Date {
+    fn partial_cmp(&self, other: &Date) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl Serialize for Date {
+    /// a "YYYY-MM-DD" string, or for formats that are not human readable
+    /// the i32 days since 1970-01-01. Both forms are stable across versions.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
+        if !serializer.is_human_readable() {
+            // Date::MIN...Date::MAX fits in i32 days
+            return serializer.serialize_i32(self.days_since_epoch() as i32);
+        }
         serializer.serialize_str(&self.to_string())
     }
 }
 
 impl<'de> Deserialize<'de> for Date {
+    /// a "YYYY-MM-DD" string or its bytes, without allocating,
+    /// or the i32 days written for formats that are not human readable,
+    /// see `fastdate::serde::Lenient` for numbers
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        use serde::de::Error;
-        Date::from_str(&String::deserialize(deserializer)?)
-            .map_err(|e| D::Error::custom(e.to_string()))
+        if !deserializer.is_human_readable() {
+            let days = i32::deserialize(deserializer)?;
+            return Date::try_from_days_since_epoch(days as i64)
+                .map_err(|e| de::Error::custom(e.to_string()));
+        }
+        deserializer.deserialize_str(DateVisitor { lenient: false })
+    }
+}
+
+/// reads a Date from a string or bytes, and when lenient from days since 1970-01-01
+pub(crate) struct DateVisitor {
+    pub(crate) lenient: bool,
+}
+
+impl Visitor<'_> for DateVisitor {
+    type Value = Date;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        match self.lenient {
+            true => f.write_str("a date string or days since 1970-01-01"),
+            false => f.write_str("a date string"),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Date, E> {
+        Date::from_str(v).map_err(|e| E::custom(e.to_string()))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Date, E> {
+        let v = std::str::from_utf8(v)
+            .map_err(|_| E::invalid_value(de::Unexpected::Bytes(v), &self))?;
+        self.visit_str(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Date, E> {
+        if !self.lenient {
+            return Err(E::invalid_type(de::Unexpected::Signed(v), &self));
+        }
+        Date::try_from_days_since_epoch(v).map_err(|e| E::custom(e.to_string()))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Date, E> {
+        let days =
+            i64::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))?;
+        self.visit_i64(days)
     }
 }
 
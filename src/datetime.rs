@@ -1,6 +1,9 @@
+use crate::date::{UNIX_EPOCH_JULIAN_DAY, UNIX_EPOCH_MJD, parse_expanded_year, write_year};
 use crate::error::Error;
 use crate::sys::Timespec;
-use crate::{Date, Time};
+use crate::time::{Align, align_nanos};
+use crate::{CalendarUnit, Date, HumanDuration, LeapSecond, RoundMode, Time, TimeUnit};
+use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::cmp;
 use std::fmt::{Display, Formatter};
@@ -11,6 +14,39 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use time1::UtcOffset;
 use time1::format_description::well_known::Rfc3339;
 
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+/// unix nanoseconds of julian day 0, -4713-11-24T12:00:00Z
+const JULIAN_DAY_EPOCH_NANOS: i128 =
+    -(UNIX_EPOCH_JULIAN_DAY as i128 * NANOS_PER_DAY - NANOS_PER_DAY / 2);
+/// unix nanoseconds of MJD 0, 1858-11-17T00:00:00Z
+const MJD_EPOCH_NANOS: i128 = -(UNIX_EPOCH_MJD as i128 * NANOS_PER_DAY);
+
+/// split a fractional day number into whole days and nanoseconds of the day
+fn split_fractional_day(v: f64) -> Result<(i64, u64), Error> {
+    if !v.is_finite() || v.abs() > i64::MAX as f64 / 2.0 {
+        return Err(Error::from("OutOfRangeDay"));
+    }
+    let day = v.floor();
+    let nano = ((v - day) * NANOS_PER_DAY as f64).round() as u64;
+    // a fraction just below 1 rounds up to a whole day
+    if nano >= NANOS_PER_DAY as u64 {
+        return Ok((day as i64 + 1, 0));
+    }
+    Ok((day as i64, nano))
+}
+
+/// A duration that can be negative, the result of `DateTime - DateTime`.
+///
+/// This is `time1::Duration`, convert with `std::time::Duration` using the checked `TryFrom` impls:
+/// ```rust
+/// use fastdate::SignedDuration;
+/// let d = SignedDuration::seconds(-90);
+/// assert!(std::time::Duration::try_from(d).is_err());
+/// let d = SignedDuration::try_from(std::time::Duration::from_secs(90)).unwrap();
+/// assert_eq!(d.whole_minutes(), 1);
+/// ```
+pub type SignedDuration = time1::Duration;
+
 static GLOBAL_OFFSET: AtomicI32 = AtomicI32::new(-99999);
 
 /// offset with utc 0.zone
@@ -32,12 +68,31 @@ pub fn set_offset_sec(sec: i32) {
 /// Format using the `Display` trait.
 /// Convert timestamp into/from `SystemTime` to use.
 /// Supports compare and sorting.
+///
+/// A leap second "23:59:60.5Z" is held as `inner` 23:59:59.5 plus a leap second flag,
+/// `sec()` is 59 and `nano()` is 1_500_000_000 like `Time`.
+/// unix timestamps of a leap second repeat the second before it,
+/// and arithmetic (add/sub/set_offset aside) drops it, so 23:59:60.5 + 1s is 00:00:00.5.
+/// `inner` is private so the flag stays in sync, see `offset_date_time` for the `time` value.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct DateTime {
-    pub inner: time1::OffsetDateTime,
+    inner: time1::OffsetDateTime,
+    leap_second: bool,
 }
 
 impl DateTime {
+    /// the earliest supported value, "-999999-01-01T00:00:00Z"
+    pub const MIN: DateTime = DateTime {
+        inner: time1::PrimitiveDateTime::MIN.assume_utc(),
+        leap_second: false,
+    };
+
+    /// the latest supported value, "+999999-12-31T23:59:59.999999999Z"
+    pub const MAX: DateTime = DateTime {
+        inner: time1::PrimitiveDateTime::MAX.assume_utc(),
+        leap_second: false,
+    };
+
     ///utc time
     pub fn utc() -> Self {
         Self::from_system_time(SystemTime::now(), 0)
@@ -47,6 +102,87 @@ impl DateTime {
         Self::from_system_time(SystemTime::now(), 0).set_offset(offset_sec())
     }
 
+    /// new from a local Date and Time at offset_sec, or an error when either is not valid
+    /// or offset_sec is out of -86399...86399
+    /// ```rust
+    /// let date = fastdate::Date::new(2022, 12, 13).unwrap();
+    /// let time = fastdate::Time::new(11, 12, 14, 0).unwrap();
+    /// let dt = fastdate::DateTime::new(date, time, 8 * 3600).unwrap();
+    /// assert_eq!(dt.to_string(), "2022-12-13T11:12:14+08:00");
+    /// ```
+    pub fn new(date: Date, time: Time, offset_sec: i32) -> Result<DateTime, Error> {
+        if !(-86399..=86399).contains(&offset_sec) {
+            return Err(Error::E("OutOfRangeOffset".to_string()));
+        }
+        date.check()?;
+        time.check()?;
+        let leap_second = time.is_leap_second();
+        let date = time1::Date::from_calendar_date(
+            date.year,
+            time1::Month::try_from(date.mon).map_err(|e| Error::from(e.to_string()))?,
+            date.day,
+        )
+        .map_err(|e| Error::from(e.to_string()))?;
+        let time =
+            time1::Time::from_hms_nano(time.hour, time.minute, time.sec, time.nano % 1_000_000_000)
+                .map_err(|e| Error::from(e.to_string()))?;
+        let offset =
+            UtcOffset::from_whole_seconds(offset_sec).map_err(|e| Error::from(e.to_string()))?;
+        let inner = time1::PrimitiveDateTime::new(date, time).assume_offset(offset);
+        if leap_second {
+            return Self::with_leap_second(inner, LeapSecond::Keep);
+        }
+        Ok(Self {
+            inner,
+            leap_second: false,
+        })
+    }
+
+    /// a value one second after inner (second 59), a leap second, checked to be 23:59:60 UTC
+    fn with_leap_second(inner: time1::OffsetDateTime, policy: LeapSecond) -> Result<Self, Error> {
+        let utc = inner.to_offset(UtcOffset::UTC);
+        if utc.hour() != 23 || utc.minute() != 59 || utc.second() != 59 {
+            return Err(Error::from("OutOfRangeSecond"));
+        }
+        match policy {
+            LeapSecond::Reject => Err(Error::from("OutOfRangeSecond")),
+            LeapSecond::Clamp => Ok(Self {
+                inner: inner
+                    .replace_nanosecond(999_999_999)
+                    .map_err(|e| Error::from(e.to_string()))?,
+                leap_second: false,
+            }),
+            LeapSecond::Keep => Ok(Self {
+                inner,
+                leap_second: true,
+            }),
+        }
+    }
+
+    /// parse an RFC3339 string, second 60 is handled by policy
+    fn parse_rfc3339(v: &mut [u8], arg: &str, policy: LeapSecond) -> Result<Self, Error> {
+        let leap = v.get(17..19) == Some(b"60");
+        if leap {
+            v[17..19].copy_from_slice(b"59");
+        }
+        let str = std::str::from_utf8(v).unwrap_or_default();
+        let inner = time1::OffsetDateTime::parse(str, &Rfc3339)
+            .map_err(|e| Error::from(format!("{} of '{}'", e, arg)))?;
+        if leap {
+            return Self::with_leap_second(inner, policy)
+                .map_err(|e| Error::from(format!("{} of '{}'", e, arg)));
+        }
+        Ok(Self {
+            inner,
+            leap_second: false,
+        })
+    }
+
+    /// is this value within a leap second, displayed as second 60?
+    pub fn is_leap_second(&self) -> bool {
+        self.leap_second
+    }
+
     /// set offset
     /// ```rust
     /// let mut  dt = fastdate::DateTime::utc();
@@ -60,25 +196,190 @@ impl DateTime {
         self
     }
 
-    /// add Duration
+    /// add Duration, panics when out of range, see `checked_add` and `saturating_add`
+    #[allow(clippy::should_implement_trait)]
     pub fn add(mut self, d: Duration) -> Self {
         self.inner = self.inner.add(d);
+        self.leap_second = false;
         self
     }
 
-    /// sub Duration
+    /// sub Duration, panics when out of range, see `checked_sub` and `saturating_sub`
+    #[allow(clippy::should_implement_trait)]
     pub fn sub(mut self, d: Duration) -> Self {
         self.inner = self.inner.sub(d);
+        self.leap_second = false;
+        self
+    }
+
+    /// add Duration, `None` when out of range
+    pub fn checked_add(self, d: Duration) -> Option<Self> {
+        self.checked_add_signed(SignedDuration::try_from(d).ok()?)
+    }
+
+    /// sub Duration, `None` when out of range
+    pub fn checked_sub(self, d: Duration) -> Option<Self> {
+        self.checked_sub_signed(SignedDuration::try_from(d).ok()?)
+    }
+
+    /// add SignedDuration, `None` when out of range
+    pub fn checked_add_signed(self, d: SignedDuration) -> Option<Self> {
+        self.inner.checked_add(d).map(Self::from)
+    }
+
+    /// sub SignedDuration, `None` when out of range
+    pub fn checked_sub_signed(self, d: SignedDuration) -> Option<Self> {
+        self.inner.checked_sub(d).map(Self::from)
+    }
+
+    /// add Duration, saturating at `DateTime::MAX`
+    /// ```rust
+    /// let dt = fastdate::DateTime::from_timestamp(0);
+    /// let v = dt.saturating_add(std::time::Duration::MAX);
+    /// assert_eq!(v, fastdate::DateTime::MAX);
+    /// ```
+    pub fn saturating_add(self, d: Duration) -> Self {
+        let offset = self.inner.offset();
+        self.checked_add(d)
+            .unwrap_or_else(|| Self::saturate(true, offset))
+    }
+
+    /// sub Duration, saturating at `DateTime::MIN`
+    pub fn saturating_sub(self, d: Duration) -> Self {
+        let offset = self.inner.offset();
+        self.checked_sub(d)
+            .unwrap_or_else(|| Self::saturate(false, offset))
+    }
+
+    /// add SignedDuration, saturating at `DateTime::MIN` or `DateTime::MAX`
+    pub fn saturating_add_signed(self, d: SignedDuration) -> Self {
+        let offset = self.inner.offset();
+        self.checked_add_signed(d)
+            .unwrap_or_else(|| Self::saturate(d.is_positive(), offset))
+    }
+
+    /// sub SignedDuration, saturating at `DateTime::MIN` or `DateTime::MAX`
+    pub fn saturating_sub_signed(self, d: SignedDuration) -> Self {
+        let offset = self.inner.offset();
+        self.checked_sub_signed(d)
+            .unwrap_or_else(|| Self::saturate(d.is_negative(), offset))
+    }
+
+    /// `DateTime::MAX` (or `DateTime::MIN`) at offset, when that is in range
+    fn saturate(max: bool, offset: UtcOffset) -> Self {
+        let v = if max { Self::MAX } else { Self::MIN };
+        match v.inner.checked_to_offset(offset) {
+            Some(inner) => Self::from(inner),
+            None => v,
+        }
+    }
+
+    /// add SignedDuration, a negative duration moves back in time
+    pub fn add_signed(mut self, d: SignedDuration) -> Self {
+        self.inner += d;
+        self.leap_second = false;
+        self
+    }
+
+    /// sub SignedDuration, a negative duration moves forward in time
+    pub fn sub_signed(mut self, d: SignedDuration) -> Self {
+        self.inner -= d;
+        self.leap_second = false;
         self
     }
 
+    /// the absolute difference of two DateTime, leap seconds are not counted
+    /// ```rust
+    /// use std::str::FromStr;
+    /// let a = fastdate::DateTime::from_str("2022-12-13T11:12:14Z").unwrap();
+    /// let b = fastdate::DateTime::from_str("2022-12-13T11:12:15Z").unwrap();
+    /// assert_eq!(a.abs_diff(&b), std::time::Duration::from_secs(1));
+    /// assert_eq!(b.abs_diff(&a), std::time::Duration::from_secs(1));
+    /// ```
+    pub fn abs_diff(&self, other: &DateTime) -> Duration {
+        (self.inner - other.inner).unsigned_abs()
+    }
+
+    /// self relative to other for people, "3 minutes ago" or "in 2 days",
+    /// use `HumanDuration::format_relative` for other styles and locales
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use fastdate::DateTime;
+    /// let now = DateTime::from_str("2022-12-13T11:12:14Z").unwrap();
+    /// let v = DateTime::from_str("2022-12-13T11:09:14Z").unwrap();
+    /// assert_eq!(v.humanize_relative_to(&now), "3 minutes ago");
+    /// assert_eq!(now.humanize_relative_to(&v), "in 3 minutes");
+    /// ```
+    pub fn humanize_relative_to(&self, other: &DateTime) -> String {
+        HumanDuration::new().format_relative(self.clone() - other.clone())
+    }
+
+    /// midnight at the start of the unit, at the offset of self,
+    /// saturating at `DateTime::MIN`
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use fastdate::{CalendarUnit, DateTime};
+    /// let dt = DateTime::from_str("2022-12-13T01:12:14+08:00").unwrap();
+    /// assert_eq!(dt.start_of(CalendarUnit::Month).to_string(), "2022-12-01T00:00:00+08:00");
+    /// ```
+    pub fn start_of(&self, unit: CalendarUnit) -> Self {
+        let date = Date::from(self.clone()).start_of(unit);
+        Self::new(date, Time::from_nanos_of_day(0), self.offset()).unwrap_or(Self::MIN)
+    }
+
+    /// the last nanosecond of the unit, 23:59:59.999999999 at the offset of self,
+    /// saturating at `DateTime::MAX`
+    pub fn end_of(&self, unit: CalendarUnit) -> Self {
+        let date = Date::from(self.clone()).end_of(unit);
+        let time = Time::from_nanos_of_day(NANOS_PER_DAY as u64 - 1);
+        Self::new(date, time, self.offset()).unwrap_or(Self::MAX)
+    }
+
+    /// midnight at the start of the next unit, at the offset of self, for half-open ranges,
+    /// an error past `DateTime::MAX`
+    pub fn end_of_exclusive(&self, unit: CalendarUnit) -> Result<Self, Error> {
+        let date = Date::from(self.clone()).end_of_exclusive(unit)?;
+        Self::new(date, Time::from_nanos_of_day(0), self.offset())
+    }
+
+    /// align down to a multiple of unit, days and `TimeUnit::Every` are counted at the offset of self.
+    /// a leap second aligns as the second before it, results saturate at `DateTime::MIN`/`DateTime::MAX`
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use fastdate::{DateTime, TimeUnit};
+    /// let dt = DateTime::from_str("2022-12-13T01:12:14+08:00").unwrap();
+    /// assert_eq!(dt.truncate(TimeUnit::Day).to_string(), "2022-12-13T00:00:00+08:00");
+    /// ```
+    pub fn truncate(&self, unit: TimeUnit) -> Self {
+        self.align(unit, Align::Floor)
+    }
+
+    /// align up to a multiple of unit
+    pub fn ceil(&self, unit: TimeUnit) -> Self {
+        self.align(unit, Align::Ceil)
+    }
+
+    /// align to the nearest multiple of unit, ties are broken by mode
+    pub fn round(&self, unit: TimeUnit, mode: RoundMode) -> Self {
+        self.align(unit, Align::Round(mode))
+    }
+
+    fn align(&self, unit: TimeUnit, align: Align) -> Self {
+        let offset = self.offset() as i128 * 1_000_000_000;
+        let v = align_nanos(self.unix_timestamp_nano() + offset, unit, align) - offset;
+        let inner = time1::OffsetDateTime::from_unix_timestamp_nanos(v)
+            .ok()
+            .and_then(|inner| inner.checked_to_offset(self.inner.offset()));
+        match inner {
+            Some(inner) => Self::from(inner),
+            None if v < 0 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
     ///add/sub sec
     pub fn add_sub_sec(self, sec: i64) -> Self {
-        if sec >= 0 {
-            self.add(Duration::from_secs(sec as u64))
-        } else {
-            self.sub(Duration::from_secs((-sec) as u64))
-        }
+        self.add_signed(SignedDuration::seconds(sec))
     }
 
     /// is self before on other?
@@ -111,53 +412,59 @@ impl DateTime {
         self.inner.unix_timestamp_nanos()
     }
 
-    ///from timestamp sec
+    ///from timestamp sec, saturating at `DateTime::MIN`/`DateTime::MAX`, see `try_from_timestamp`
     pub fn from_timestamp(sec: i64) -> DateTime {
-        if sec >= 0 {
-            Self::from_system_time(UNIX_EPOCH + Duration::from_secs(sec as u64), 0)
-        } else {
-            Self::from_system_time(UNIX_EPOCH - Duration::from_secs((-sec) as u64), 0)
-        }
+        Self::try_from_timestamp(sec).unwrap_or_else(|_| Self::saturate(sec > 0, UtcOffset::UTC))
     }
-    ///from timestamp micros
+    ///from timestamp micros, saturating at `DateTime::MIN`/`DateTime::MAX`
     pub fn from_timestamp_micros(micros: i64) -> DateTime {
-        if micros >= 0 {
-            Self::from_system_time(UNIX_EPOCH + Duration::from_micros(micros as u64), 0)
-        } else {
-            Self::from_system_time(UNIX_EPOCH - Duration::from_micros((-micros) as u64), 0)
-        }
+        Self::try_from_timestamp_micros(micros)
+            .unwrap_or_else(|_| Self::saturate(micros > 0, UtcOffset::UTC))
     }
-    ///from timestamp millis
+    ///from timestamp millis, saturating at `DateTime::MIN`/`DateTime::MAX`
     pub fn from_timestamp_millis(ms: i64) -> DateTime {
-        if ms >= 0 {
-            Self::from_system_time(UNIX_EPOCH + Duration::from_millis(ms as u64), 0)
-        } else {
-            Self::from_system_time(UNIX_EPOCH - Duration::from_millis((-ms) as u64), 0)
-        }
+        Self::try_from_timestamp_millis(ms)
+            .unwrap_or_else(|_| Self::saturate(ms > 0, UtcOffset::UTC))
     }
-    ///from timestamp nano
+    ///from timestamp nano, saturating at `DateTime::MIN`/`DateTime::MAX`
     pub fn from_timestamp_nano(nano: i128) -> DateTime {
-        if nano >= 0 {
-            Self::from_system_time(UNIX_EPOCH + Duration::from_nanos(nano as u64), 0)
-        } else {
-            Self::from_system_time(UNIX_EPOCH - Duration::from_nanos((-nano) as u64), 0)
-        }
+        Self::try_from_timestamp_nano(nano)
+            .unwrap_or_else(|_| Self::saturate(nano > 0, UtcOffset::UTC))
+    }
+
+    /// from timestamp sec, or an error when out of range
+    pub fn try_from_timestamp(sec: i64) -> Result<DateTime, Error> {
+        Self::try_from_timestamp_nano(sec as i128 * 1_000_000_000)
+    }
+
+    /// from timestamp micros, or an error when out of range
+    pub fn try_from_timestamp_micros(micros: i64) -> Result<DateTime, Error> {
+        Self::try_from_timestamp_nano(micros as i128 * 1_000)
+    }
+
+    /// from timestamp millis, or an error when out of range
+    pub fn try_from_timestamp_millis(ms: i64) -> Result<DateTime, Error> {
+        Self::try_from_timestamp_nano(ms as i128 * 1_000_000)
+    }
+
+    /// from timestamp nano, or an error when out of range
+    /// ```rust
+    /// assert!(fastdate::DateTime::try_from_timestamp_nano(0).is_ok());
+    /// assert!(fastdate::DateTime::try_from_timestamp_nano(i128::MAX).is_err());
+    /// ```
+    pub fn try_from_timestamp_nano(nano: i128) -> Result<DateTime, Error> {
+        let inner = time1::OffsetDateTime::from_unix_timestamp_nanos(nano)
+            .map_err(|e| Error::from(e.to_string()))?;
+        Ok(Self::from(inner))
     }
 
     /// format support token = ["YYYY","MM","DD","hh","mm","ss",".000000",".000000000","+00:00"]
     /// ```
-    /// let dt = fastdate::DateTime::from((
-    ///         fastdate::Date {
-    ///             day: 1,
-    ///             mon: 1,
-    ///             year: 2000,
-    ///         },
-    ///         fastdate::Time {
-    ///             nano: 123456000,
-    ///             sec: 11,
-    ///             minute: 1,
-    ///             hour: 1,
-    ///         })).set_offset(8 * 60 * 60);
+    /// let dt = fastdate::DateTime::new(
+    ///         fastdate::Date::new(2000, 1, 1).unwrap(),
+    ///         fastdate::Time::new(1, 1, 11, 123456000).unwrap(),
+    ///         0,
+    ///     ).unwrap().set_offset(8 * 60 * 60);
     ///   println!("{}",dt.format("YYYY-MM-DD hh:mm:ss"));
     ///   println!("{}",dt.format("YYYY-MM-DD hh:mm:ss.000000"));
     ///   println!("{}",dt.format("YYYY-MM-DD hh:mm:ss.000000+00:00"));
@@ -170,6 +477,11 @@ impl DateTime {
         let (mut h, mut m, _) = self.offset_hms();
         let offset = self.offset();
         let add_sub = if offset >= 0 { '+' } else { '-' };
+        // a leap second is written as second 60
+        let (sec, nano) = match self.leap_second {
+            true => (60, self.inner.nanosecond()),
+            false => (self.sec(), self.nano()),
+        };
         let mut result = String::with_capacity(fmt.len());
         let chars = fmt.as_bytes();
         let mut index = 0;
@@ -180,7 +492,7 @@ impl DateTime {
                 for _ in 0..".000000000".len() {
                     result.pop();
                 }
-                write!(result, ".{:09}", self.nano()).unwrap()
+                write!(result, ".{:09}", nano).unwrap()
             } else if result.ends_with(".000000") {
                 if (index + 3) < fmt.len()
                     && chars[index + 1] == b'0'
@@ -193,7 +505,7 @@ impl DateTime {
                 for _ in 0..".000000".len() {
                     result.pop();
                 }
-                write!(result, ".{:06}", self.nano() / 1000).unwrap();
+                write!(result, ".{:06}", nano / 1000).unwrap();
             } else if result.ends_with("+00:00") {
                 for _ in 0.."+00:00".len() {
                     result.pop();
@@ -205,7 +517,7 @@ impl DateTime {
                 for _ in 0.."YYYY".len() {
                     result.pop();
                 }
-                write!(result, "{:04}", self.year()).unwrap()
+                write_year(&mut result, self.year()).unwrap()
             } else if result.ends_with("MM") {
                 for _ in 0.."MM".len() {
                     result.pop();
@@ -230,7 +542,7 @@ impl DateTime {
                 for _ in 0.."ss".len() {
                     result.pop();
                 }
-                write!(result, "{:02}", self.sec()).unwrap();
+                write!(result, "{:02}", sec).unwrap();
             }
             index += 1;
         }
@@ -276,42 +588,60 @@ impl DateTime {
         //this is RFC3339 datetime buffer
         let bytes = arg.as_bytes();
         let mut buf: [u8; 35] = *b"0000-00-00T00:00:00.000000000+00:00";
+        // an expanded year "+012345" is longer than 'YYYY', the tokens after it shift right
+        let mut expanded_year = None;
+        let mut year_at = usize::MAX;
+        let mut shift = 0;
         if let Some(year) = format.find("YYYY") {
-            for (index, _) in (0..4).enumerate() {
-                buf[index] = *bytes
-                    .get(year + index)
-                    .ok_or_else(|| Error::from("warn 'YYYY'"))?;
+            year_at = year;
+            if let Some(b'+' | b'-') = bytes.get(year) {
+                let (v, len) = parse_expanded_year(&bytes[year..])
+                    .map_err(|e| Error::from(format!("{} of '{}'", e, arg)))?;
+                expanded_year = Some(v);
+                shift = len - 4;
+                buf[..4].copy_from_slice(b"2000");
+            } else {
+                for (index, _) in (0..4).enumerate() {
+                    buf[index] = *bytes
+                        .get(year + index)
+                        .ok_or_else(|| Error::from("warn 'YYYY'"))?;
+                }
             }
         }
-        if let Some(mon) = format.find("MM") {
+        let find = |token: &str| {
+            format
+                .find(token)
+                .map(|i| if i > year_at { i + shift } else { i })
+        };
+        if let Some(mon) = find("MM") {
             for (index, _) in (0..2).enumerate() {
                 buf[5 + index] = *bytes
                     .get(mon + index)
                     .ok_or_else(|| Error::from("warn 'MM'"))?;
             }
         }
-        if let Some(day) = format.find("DD") {
+        if let Some(day) = find("DD") {
             for (index, _) in (0..2).enumerate() {
                 buf[8 + index] = *bytes
                     .get(day + index)
                     .ok_or_else(|| Error::from("warn 'DD'"))?;
             }
         }
-        if let Some(hour) = format.find("hh") {
+        if let Some(hour) = find("hh") {
             for (index, _) in (0..2).enumerate() {
                 buf[11 + index] = *bytes
                     .get(hour + index)
                     .ok_or_else(|| Error::from("warn 'hh'"))?;
             }
         }
-        if let Some(minute) = format.find("mm") {
+        if let Some(minute) = find("mm") {
             for (index, _) in (0..2).enumerate() {
                 buf[14 + index] = *bytes
                     .get(minute + index)
                     .ok_or_else(|| Error::from("warn 'mm'"))?;
             }
         }
-        if let Some(sec) = format.find("ss") {
+        if let Some(sec) = find("ss") {
             for (index, _) in (0..2).enumerate() {
                 buf[17 + index] = *bytes
                     .get(sec + index)
@@ -320,7 +650,7 @@ impl DateTime {
         }
         let mut find_nano = false;
         //parse '.000000000'
-        if let Some(nano) = format.find(".000000000") {
+        if let Some(nano) = find(".000000000") {
             for index in 0..10 {
                 buf[19 + index] = *bytes
                     .get(nano + index)
@@ -329,15 +659,13 @@ impl DateTime {
             len += 10;
             find_nano = true;
         }
-        if !find_nano {
-            if let Some(micro) = format.find(".000000") {
-                for index in 0..7 {
-                    buf[19 + index] = *bytes
-                        .get(micro + index)
-                        .ok_or_else(|| Error::from("warn '.000000'"))?;
-                }
-                len += 7;
+        if !find_nano && let Some(micro) = find(".000000") {
+            for index in 0..7 {
+                buf[19 + index] = *bytes
+                    .get(micro + index)
+                    .ok_or_else(|| Error::from("warn '.000000'"))?;
             }
+            len += 7;
         }
         let mut have_offset = false;
         if format.contains("Z") {
@@ -345,7 +673,7 @@ impl DateTime {
             len += 1;
             have_offset = true;
         }
-        if let Some(zone) = format.find("+00:00") {
+        if let Some(zone) = find("+00:00") {
             for index in 0..6 {
                 let x = bytes
                     .get(zone + index)
@@ -377,10 +705,87 @@ impl DateTime {
             buf[len] = b'0' + (m.abs() % 10) as u8;
             len += 1;
         }
-        let str = std::str::from_utf8(&buf[..len]).unwrap_or_default();
-        let inner = time1::OffsetDateTime::parse(str, &Rfc3339)
-            .map_err(|e| Error::from(format!("{} of '{}'", e, arg)))?;
-        Ok(Self { inner })
+        let mut datetime = Self::parse_rfc3339(&mut buf[..len], arg, LeapSecond::Keep)?;
+        if let Some(year) = expanded_year {
+            datetime.inner = datetime
+                .inner
+                .replace_year(year)
+                .map_err(|e| Error::from(format!("{} of '{}'", e, arg)))?;
+        }
+        Ok(datetime)
+    }
+
+    /// Julian Date of this instant (UTC), the day part and the nanoseconds since noon.
+    /// exact, round-trips with `from_julian_day_parts`
+    /// ```rust
+    /// use std::str::FromStr;
+    /// let dt = fastdate::DateTime::from_str("2022-12-13T00:00:00Z").unwrap();
+    /// assert_eq!(dt.to_julian_day_parts(), (2459926, 43_200_000_000_000));
+    /// ```
+    pub fn to_julian_day_parts(&self) -> (i64, u64) {
+        self.day_parts(JULIAN_DAY_EPOCH_NANOS)
+    }
+
+    /// from a Julian Date day part and nanoseconds since noon, as UTC,
+    /// an error when nano is not less than a day
+    pub fn from_julian_day_parts(day: i64, nano: u64) -> Result<DateTime, Error> {
+        Self::from_day_parts(JULIAN_DAY_EPOCH_NANOS, day, nano)
+    }
+
+    /// fractional Julian Date of this instant (UTC), e.g. 2459926.5.
+    /// f64 only holds about a tenth of a millisecond at this magnitude, use `to_julian_day_parts` to keep nanoseconds
+    pub fn to_julian_day(&self) -> f64 {
+        let (day, nano) = self.to_julian_day_parts();
+        day as f64 + nano as f64 / NANOS_PER_DAY as f64
+    }
+
+    /// from a fractional Julian Date, as UTC
+    pub fn from_julian_day(jd: f64) -> Result<DateTime, Error> {
+        let (day, nano) = split_fractional_day(jd)?;
+        Self::from_julian_day_parts(day, nano)
+    }
+
+    /// Modified Julian Date of this instant (UTC), the day part and the nanoseconds since midnight.
+    /// exact, round-trips with `from_mjd_parts`
+    pub fn to_mjd_parts(&self) -> (i64, u64) {
+        self.day_parts(MJD_EPOCH_NANOS)
+    }
+
+    /// from a Modified Julian Date day part and nanoseconds since midnight, as UTC,
+    /// an error when nano is not less than a day
+    pub fn from_mjd_parts(day: i64, nano: u64) -> Result<DateTime, Error> {
+        Self::from_day_parts(MJD_EPOCH_NANOS, day, nano)
+    }
+
+    /// fractional Modified Julian Date of this instant (UTC), e.g. 59926.25
+    pub fn to_mjd(&self) -> f64 {
+        let (day, nano) = self.to_mjd_parts();
+        day as f64 + nano as f64 / NANOS_PER_DAY as f64
+    }
+
+    /// from a fractional Modified Julian Date, as UTC
+    pub fn from_mjd(mjd: f64) -> Result<DateTime, Error> {
+        let (day, nano) = split_fractional_day(mjd)?;
+        Self::from_mjd_parts(day, nano)
+    }
+
+    /// whole days and nanoseconds since an epoch given in unix nanoseconds
+    fn day_parts(&self, epoch_nanos: i128) -> (i64, u64) {
+        let nanos = self.unix_timestamp_nano() - epoch_nanos;
+        (
+            nanos.div_euclid(NANOS_PER_DAY) as i64,
+            nanos.rem_euclid(NANOS_PER_DAY) as u64,
+        )
+    }
+
+    fn from_day_parts(epoch_nanos: i128, day: i64, nano: u64) -> Result<DateTime, Error> {
+        if nano as i128 >= NANOS_PER_DAY {
+            return Err(Error::E("OutOfRangeNano".to_string()));
+        }
+        let nanos = day as i128 * NANOS_PER_DAY + nano as i128 + epoch_nanos;
+        let inner = time1::OffsetDateTime::from_unix_timestamp_nanos(nanos)
+            .map_err(|e| Error::from(e.to_string()))?;
+        Ok(Self::from(inner))
     }
 
     /// get week_day
@@ -396,17 +801,19 @@ impl DateTime {
         wday as u8
     }
 
+    /// get nano, 1_000_000_000...1_999_999_999 within a leap second
     pub fn nano(&self) -> u32 {
-        self.inner.nanosecond()
+        self.inner.nanosecond() + if self.leap_second { 1_000_000_000 } else { 0 }
     }
 
+    /// get milli secs, 1000...1999 within a leap second
     pub fn ms(&self) -> u16 {
-        self.inner.millisecond()
+        self.inner.millisecond() + if self.leap_second { 1000 } else { 0 }
     }
 
-    /// get micro secs
+    /// get micro secs, 1000000...1999999 within a leap second
     pub fn micro(&self) -> u32 {
-        self.inner.microsecond()
+        self.inner.microsecond() + if self.leap_second { 1_000_000 } else { 0 }
     }
 
     /// get sec
@@ -439,6 +846,11 @@ impl DateTime {
         self.inner.year()
     }
 
+    /// the `time` crate value, a leap second is 23:59:59 plus its fraction
+    pub fn offset_date_time(&self) -> time1::OffsetDateTime {
+        self.inner
+    }
+
     ///offset sec
     pub fn offset(&self) -> i32 {
         self.inner.offset().whole_seconds()
@@ -450,23 +862,21 @@ impl DateTime {
     }
 
     pub fn from_system_time(s: SystemTime, offset: i32) -> Self {
-        Self {
-            inner: time1::OffsetDateTime::from(s),
-        }
-        .set_offset(offset)
+        Self::from(time1::OffsetDateTime::from(s)).set_offset(offset)
     }
 
     /// stand "0000-00-00 00:00:00.000000000"
     pub fn display_stand(&self) -> String {
         let mut v = self.display(false);
-        v.replace_range(10..11, " ");
+        let t = v.find('T').unwrap_or(10);
+        v.replace_range(t..t + 1, " ");
         v
     }
 
     /// RFC3339 "0000-00-00T00:00:00.000000000Z"
     /// RFC3339 "0000-00-00T00:00:00.000000000+00:00:00"
     pub fn display(&self, zone: bool) -> String {
-        let mut buf: [u8; 38] = *b"0000-00-00T00:00:00.000000000+00:00:00";
+        let mut buf = [0u8; 41];
         let len = self.do_display(&mut buf, zone);
         std::str::from_utf8(&buf[..len]).unwrap().to_string()
     }
@@ -475,20 +885,13 @@ impl DateTime {
     /// than print this:
     /// RFC3339 "0000-00-00T00:00:00.000000000Z"
     /// RFC3339 "0000-00-00T00:00:00.000000000+00:00:00"
-    pub fn do_display(&self, buf: &mut [u8; 38], add_zone: bool) -> usize {
-        let year = self.year();
-        let mon = self.mon();
-        let day = self.day();
-        buf[0] = b'0' + (year / 1000) as u8;
-        buf[1] = b'0' + (year / 100 % 10) as u8;
-        buf[2] = b'0' + (year / 10 % 10) as u8;
-        buf[3] = b'0' + (year % 10) as u8;
-        buf[5] = b'0' + (mon / 10);
-        buf[6] = b'0' + (mon % 10);
-        buf[8] = b'0' + (day / 10);
-        buf[9] = b'0' + (day % 10);
+    ///
+    /// a year out of 0000...9999 is expanded to "+YYYYYY", buf then needs 41 bytes
+    pub fn do_display(&self, buf: &mut [u8], add_zone: bool) -> usize {
+        let len = Date::from(self.clone()).display_date(0, buf);
+        buf[len] = b'T';
         let time = Time::from(self.clone());
-        let mut len = time.display_time(11, buf);
+        let mut len = time.display_time(len + 1, buf);
         if add_zone {
             let offset = self.offset();
             if offset == 0 {
@@ -544,19 +947,105 @@ impl DateTime {
         len
     }
 
-    pub fn set_nano(mut self, nano: u32) -> Self {
-        let v = self.nano();
-        if nano != v {
-            self = self.sub(Duration::from_nanos(v as u64));
-            self = self.add(Duration::from_micros(nano as u64));
-        }
-        self
+    /// set nano like `with_nano`, ignored when out of range
+    pub fn set_nano(self, nano: u32) -> Self {
+        self.clone().with_nano(nano).unwrap_or(self)
+    }
+
+    /// set year at the local offset, or an error when the date would not be valid
+    /// ```rust
+    /// use std::str::FromStr;
+    /// let dt = fastdate::DateTime::from_str("2024-02-29T11:12:14+08:00").unwrap();
+    /// assert_eq!(dt.clone().with_year(2028).unwrap().to_string(), "2028-02-29T11:12:14+08:00");
+    /// assert!(dt.with_year(2023).is_err());
+    /// ```
+    pub fn with_year(self, arg: i32) -> Result<Self, Error> {
+        let date = Date::from(self.clone()).with_year(arg)?;
+        self.with_date(date)
+    }
+
+    /// set month at the local offset, or an error when the date would not be valid
+    pub fn with_month(self, arg: u8) -> Result<Self, Error> {
+        let date = Date::from(self.clone()).with_mon(arg)?;
+        self.with_date(date)
+    }
+
+    /// set day at the local offset, or an error when the date would not be valid
+    pub fn with_day(self, arg: u8) -> Result<Self, Error> {
+        let date = Date::from(self.clone()).with_day(arg)?;
+        self.with_date(date)
+    }
+
+    /// set hour at the local offset, or an error when out of 0...23
+    pub fn with_hour(self, arg: u8) -> Result<Self, Error> {
+        let time = Time::from(self.clone()).with_hour(arg)?;
+        self.with_time(time)
+    }
+
+    /// set minute at the local offset, or an error when out of 0...59
+    pub fn with_minute(self, arg: u8) -> Result<Self, Error> {
+        let time = Time::from(self.clone()).with_minute(arg)?;
+        self.with_time(time)
+    }
+
+    /// set second, or an error when out of 0...59, a leap second becomes an ordinary second
+    pub fn with_second(self, arg: u8) -> Result<Self, Error> {
+        let time = Time::from(self.clone()).with_sec(arg)?;
+        self.with_time(time)
+    }
+
+    /// set nano, or an error when out of 0...999999999
+    /// (up to 1999999999 for a leap second at 23:59:59 UTC)
+    pub fn with_nano(self, arg: u32) -> Result<Self, Error> {
+        let time = Time::from(self.clone()).with_nano(arg)?;
+        self.with_time(time)
+    }
+
+    /// set the date at the local offset, keeping the time of day
+    pub fn with_date(self, date: Date) -> Result<Self, Error> {
+        let offset = self.offset();
+        Self::new(date, Time::from(self), offset)
+    }
+
+    /// set the time of day at the local offset, keeping the date
+    pub fn with_time(self, time: Time) -> Result<Self, Error> {
+        let offset = self.offset();
+        Self::new(Date::from(self), time, offset)
     }
 
     pub fn from_str_default(arg: &str, default_offset: i32) -> Result<DateTime, Error> {
+        Self::parse_str(arg, default_offset, LeapSecond::Keep)
+    }
+
+    /// parse like `FromStr`, with a policy for a leap second "23:59:60Z"
+    /// ```rust
+    /// use fastdate::{DateTime, LeapSecond};
+    /// let dt = DateTime::from_str_leap("2016-12-31T23:59:60.5Z", LeapSecond::Keep).unwrap();
+    /// assert_eq!(dt.to_string(), "2016-12-31T23:59:60.5Z");
+    /// let dt = DateTime::from_str_leap("2016-12-31T23:59:60.5Z", LeapSecond::Clamp).unwrap();
+    /// assert_eq!(dt.to_string(), "2016-12-31T23:59:59.999999999Z");
+    /// ```
+    pub fn from_str_leap(arg: &str, policy: LeapSecond) -> Result<DateTime, Error> {
+        Self::parse_str(arg, offset_sec(), policy)
+    }
+
+    fn parse_str(arg: &str, default_offset: i32, policy: LeapSecond) -> Result<DateTime, Error> {
+        // ISO 8601 expanded year "+012345-01-01" or "-0044-03-15",
+        // parsed with a leap year placeholder and replaced at the end
+        let mut expanded_year = None;
+        let mut rest = arg;
+        if arg.starts_with(['+', '-']) {
+            let (year, len) = parse_expanded_year(arg.as_bytes())
+                .map_err(|e| Error::from(format!("{} of '{}'", e, arg)))?;
+            expanded_year = Some(year);
+            rest = &arg[len..];
+        }
         let mut v = {
-            let mut v = String::with_capacity(arg.len() + 6);
-            for x in arg.chars() {
+            let mut v = String::with_capacity(rest.len() + 10);
+            if expanded_year.is_some() {
+                v.push_str("2000");
+            }
+            for x in rest.chars() {
                 v.push(x);
             }
             v
@@ -589,12 +1078,12 @@ impl DateTime {
                 }
             }
         }
-        if let Some(mut offset) = have_offset {
-            if offset >= 1 {
-                offset -= 1;
-                if v.len() > offset && &v[offset..(offset + 1)] == " " {
-                    v.remove(offset);
-                }
+        if let Some(mut offset) = have_offset
+            && offset >= 1
+        {
+            offset -= 1;
+            if v.len() > offset && &v[offset..(offset + 1)] == " " {
+                v.remove(offset);
             }
         }
         if have_offset.is_none() {
@@ -606,9 +1095,15 @@ impl DateTime {
                 v.push_str(&format!("-{:02}:{:02}", h.abs(), m.abs()));
             }
         }
-        let inner = time1::OffsetDateTime::parse(&v, &Rfc3339)
-            .map_err(|e| Error::from(format!("{} of '{}'", e, arg)))?;
-        Ok(Self { inner })
+        let mut v = v.into_bytes();
+        let mut datetime = Self::parse_rfc3339(&mut v, arg, policy)?;
+        if let Some(year) = expanded_year {
+            datetime.inner = datetime
+                .inner
+                .replace_year(year)
+                .map_err(|e| Error::from(format!("{} of '{}'", e, arg)))?;
+        }
+        Ok(datetime)
     }
 }
 
@@ -644,12 +1139,44 @@ impl Sub<&Duration> for DateTime {
     }
 }
 
+impl Add<SignedDuration> for DateTime {
+    type Output = DateTime;
+
+    fn add(self, rhs: SignedDuration) -> Self::Output {
+        self.add_signed(rhs)
+    }
+}
+
+impl Sub<SignedDuration> for DateTime {
+    type Output = DateTime;
+
+    fn sub(self, rhs: SignedDuration) -> Self::Output {
+        self.sub_signed(rhs)
+    }
+}
+
+impl Add<&SignedDuration> for DateTime {
+    type Output = DateTime;
+
+    fn add(self, rhs: &SignedDuration) -> Self::Output {
+        self.add_signed(*rhs)
+    }
+}
+
+impl Sub<&SignedDuration> for DateTime {
+    type Output = DateTime;
+
+    fn sub(self, rhs: &SignedDuration) -> Self::Output {
+        self.sub_signed(*rhs)
+    }
+}
+
+/// signed difference, negative when rhs is later, leap seconds are not counted
 impl Sub<DateTime> for DateTime {
-    type Output = Duration;
+    type Output = SignedDuration;
 
     fn sub(self, rhs: DateTime) -> Self::Output {
-        let nano = self.unix_timestamp_nano() - rhs.unix_timestamp_nano();
-        Duration::from_nanos(nano as u64)
+        self.inner - rhs.inner
     }
 }
 
@@ -662,21 +1189,19 @@ impl From<SystemTime> for DateTime {
 impl From<DateTime> for SystemTime {
     fn from(v: DateTime) -> SystemTime {
         let nano = v.unix_timestamp_nano();
+        let abs = nano.unsigned_abs();
+        let d = Duration::new((abs / 1_000_000_000) as u64, (abs % 1_000_000_000) as u32);
         if nano >= 0 {
-            UNIX_EPOCH + Duration::from_nanos(nano as u64)
+            UNIX_EPOCH + d
         } else {
-            UNIX_EPOCH - Duration::from_nanos(nano as u64)
+            UNIX_EPOCH - d
         }
     }
 }
 
 impl From<Date> for DateTime {
     fn from(arg: Date) -> Self {
-        Self::from_str(&format!(
-            "{:04}-{:02}-{:02} 00:00:00.000000000Z",
-            arg.year, arg.mon, arg.day
-        ))
-        .unwrap()
+        Self::new(arg, Time::new(0, 0, 0, 0).unwrap(), 0).unwrap()
     }
 }
 
@@ -689,36 +1214,31 @@ impl From<(Date, i32)> for DateTime {
     }
 }
 
-impl From<Time> for DateTime {
-    fn from(arg: Time) -> Self {
-        Self::from_str(&format!(
-            "0000-01-01 {:02}:{:02}:{:02}.{:09}Z",
-            arg.hour, arg.minute, arg.sec, arg.nano
-        ))
-        .unwrap()
+/// the time on 0000-01-01 at offset 0, an error for a leap second that is not 23:59:60
+impl TryFrom<Time> for DateTime {
+    type Error = Error;
+
+    fn try_from(arg: Time) -> Result<Self, Self::Error> {
+        Self::new(Date::new(0, 1, 1)?, arg, 0)
     }
 }
 
-impl From<(Date, Time)> for DateTime {
-    fn from(arg: (Date, Time)) -> Self {
-        Self::from_str(&format!(
-            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:09}Z",
-            arg.0.year, arg.0.mon, arg.0.day, arg.1.hour, arg.1.minute, arg.1.sec, arg.1.nano
-        ))
-        .unwrap()
+/// at offset 0, same as `DateTime::new`
+impl TryFrom<(Date, Time)> for DateTime {
+    type Error = Error;
+
+    fn try_from(arg: (Date, Time)) -> Result<Self, Self::Error> {
+        Self::new(arg.0, arg.1, 0)
     }
 }
 
-///from(Date{},Time{},offset_sec())
-impl From<(Date, Time, i32)> for DateTime {
-    fn from(arg: (Date, Time, i32)) -> Self {
-        let mut datetime = Self::from_str(&format!(
-            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:09}Z",
-            arg.0.year, arg.0.mon, arg.0.day, arg.1.hour, arg.1.minute, arg.1.sec, arg.1.nano
-        ))
-        .unwrap();
-        datetime = datetime.set_offset(arg.2).add_sub_sec(-arg.2 as i64);
-        datetime
+/// try_from((Date, Time, offset_sec())), an error when the offset is out of -86399...86399,
+/// same as `DateTime::new`
+impl TryFrom<(Date, Time, i32)> for DateTime {
+    type Error = Error;
+
+    fn try_from(arg: (Date, Time, i32)) -> Result<Self, Self::Error> {
+        Self::new(arg.0, arg.1, arg.2)
     }
 }
 
@@ -739,7 +1259,7 @@ impl FromStr for DateTime {
 impl Display for DateTime {
     /// fmt RFC3339Nano = "2006-01-02T15:04:05.999999999"
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        let mut buf: [u8; 38] = *b"0000-00-00T00:00:00.000000000+00:00:00";
+        let mut buf = [0u8; 41];
         let len = self.do_display(&mut buf, true);
         f.write_str(std::str::from_utf8(&buf[..len]).unwrap())
     }
@@ -747,32 +1267,215 @@ impl Display for DateTime {
 
 impl Ord for DateTime {
     fn cmp(&self, other: &DateTime) -> cmp::Ordering {
-        self.unix_timestamp_nano().cmp(&other.unix_timestamp_nano())
+        // a leap second sorts after the second before it, which has the same timestamp
+        (
+            self.unix_timestamp(),
+            self.leap_second,
+            self.inner.nanosecond(),
+        )
+            .cmp(&(
+                other.unix_timestamp(),
+                other.leap_second,
+                other.inner.nanosecond(),
+            ))
+    }
+}
+
+/// a leap second is 23:59:59 plus its fraction
+impl From<DateTime> for time1::OffsetDateTime {
+    fn from(v: DateTime) -> Self {
+        v.inner
+    }
+}
+
+impl From<time1::OffsetDateTime> for DateTime {
+    fn from(inner: time1::OffsetDateTime) -> Self {
+        Self {
+            inner,
+            leap_second: false,
+        }
     }
 }
 
 impl PartialOrd for DateTime {
     fn partial_cmp(&self, other: &DateTime) -> Option<cmp::Ordering> {
-        Some(self.unix_timestamp_nano().cmp(&other.unix_timestamp_nano()))
+        Some(self.cmp(other))
+    }
+}
+
+impl DateTime {
+    /// from the compact binary form, seconds since 1970-01-01T00:00:00Z,
+    /// nanos (1000000000...1999999999 in a leap second) and the offset in seconds
+    fn from_compact(secs: i64, nanos: u32, offset_sec: i32) -> Result<Self, Error> {
+        if nanos > 1_999_999_999 {
+            return Err(Error::E("OutOfRangeNano".to_string()));
+        }
+        let offset =
+            UtcOffset::from_whole_seconds(offset_sec).map_err(|e| Error::from(e.to_string()))?;
+        let inner = time1::OffsetDateTime::from_unix_timestamp_nanos(
+            secs as i128 * 1_000_000_000 + (nanos % 1_000_000_000) as i128,
+        )
+        .map_err(|e| Error::from(e.to_string()))?
+        .to_offset(offset);
+        if nanos > 999_999_999 {
+            return Self::with_leap_second(inner, LeapSecond::Keep);
+        }
+        Ok(Self {
+            inner,
+            leap_second: false,
+        })
     }
 }
 
 impl Serialize for DateTime {
+    /// an RFC 3339 string, or for formats that are not human readable the tuple
+    /// `(i64 seconds since 1970-01-01T00:00:00Z, u32 nanos, i32 offset seconds)`,
+    /// nanos are 1000000000...1999999999 in a leap second.
+    /// Both forms are stable across versions.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
+        if !serializer.is_human_readable() {
+            return (self.unix_timestamp(), self.nano(), self.offset()).serialize(serializer);
+        }
         serializer.serialize_str(&self.to_string())
     }
 }
 
 impl<'de> Deserialize<'de> for DateTime {
+    /// an RFC 3339 string or its bytes,
+    /// or the tuple written for formats that are not human readable,
+    /// see `fastdate::serde::Lenient` for numbers and maps
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        use serde::de::Error;
-        let s = String::deserialize(deserializer)?;
-        DateTime::from_str(&s).map_err(D::Error::custom)
+        if !deserializer.is_human_readable() {
+            let (secs, nanos, offset) = <(i64, u32, i32)>::deserialize(deserializer)?;
+            return DateTime::from_compact(secs, nanos, offset)
+                .map_err(|e| de::Error::custom(e.to_string()));
+        }
+        deserializer.deserialize_str(DateTimeVisitor { lenient: false })
+    }
+}
+
+/// reads a DateTime from a string or bytes, and when lenient from seconds since
+/// 1970-01-01T00:00:00Z (an integer, an integer string or a float) or a map `{"secs", "nanos"}`
+pub(crate) struct DateTimeVisitor {
+    pub(crate) lenient: bool,
+}
+
+impl<'de> Visitor<'de> for DateTimeVisitor {
+    type Value = DateTime;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self.lenient {
+            true => f.write_str("an RFC 3339 string, seconds since 1970-01-01T00:00:00Z or a map with secs and nanos"),
+            false => f.write_str("an RFC 3339 string"),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<DateTime, E> {
+        if self.lenient
+            && let Ok(secs) = i64::from_str(v)
+        {
+            return self.visit_i64(secs);
+        }
+        DateTime::from_str(v).map_err(|e| E::custom(e.to_string()))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<DateTime, E> {
+        let v = std::str::from_utf8(v)
+            .map_err(|_| E::invalid_value(de::Unexpected::Bytes(v), &self))?;
+        self.visit_str(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<DateTime, E> {
+        if !self.lenient {
+            return Err(E::invalid_type(de::Unexpected::Signed(v), &self));
+        }
+        DateTime::try_from_timestamp(v).map_err(|e| E::custom(e.to_string()))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<DateTime, E> {
+        let secs =
+            i64::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))?;
+        self.visit_i64(secs)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<DateTime, E> {
+        if !self.lenient {
+            return Err(E::invalid_type(de::Unexpected::Float(v), &self));
+        }
+        // a double is only precise to about a microsecond for recent times
+        let secs = v.floor();
+        if !secs.is_finite() || secs.abs() > 1e18 {
+            return Err(E::invalid_value(de::Unexpected::Float(v), &self));
+        }
+        let micros = ((v - secs) * 1e6).round() as i128;
+        DateTime::try_from_timestamp_nano(secs as i128 * 1_000_000_000 + micros * 1000)
+            .map_err(|e| E::custom(e.to_string()))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<DateTime, A::Error> {
+        if !self.lenient {
+            return Err(de::Error::invalid_type(de::Unexpected::Map, &self));
+        }
+        let (mut secs, mut nanos) = (None, 0u32);
+        while let Some(key) = map.next_key::<SecsNanos>()? {
+            match key {
+                SecsNanos::Secs => secs = Some(map.next_value::<i64>()?),
+                SecsNanos::Nanos => nanos = map.next_value()?,
+            }
+        }
+        let secs = secs.ok_or_else(|| de::Error::missing_field("secs"))?;
+        if nanos > 999_999_999 {
+            return Err(de::Error::custom("OutOfRangeNanos"));
+        }
+        DateTime::try_from_timestamp_nano(secs as i128 * 1_000_000_000 + nanos as i128)
+            .map_err(|e| de::Error::custom(e.to_string()))
+    }
+}
+
+/// a key of the lenient `{"secs", "nanos"}` map, read without allocating
+pub(crate) enum SecsNanos {
+    Secs,
+    Nanos,
+}
+
+impl<'de> Deserialize<'de> for SecsNanos {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["secs", "nanos"];
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = SecsNanos;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("`secs` or `nanos`")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<SecsNanos, E> {
+                match v {
+                    "secs" => Ok(SecsNanos::Secs),
+                    "nanos" => Ok(SecsNanos::Nanos),
+                    _ => Err(E::unknown_field(v, FIELDS)),
+                }
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<SecsNanos, E> {
+                match v {
+                    b"secs" => Ok(SecsNanos::Secs),
+                    b"nanos" => Ok(SecsNanos::Nanos),
+                    _ => Err(E::unknown_field(&String::from_utf8_lossy(v), FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
     }
 }
@@ -0,0 +1,278 @@
+use crate::time::{Align, align_nanos};
+use crate::{RoundMode, SignedDuration, TimeUnit};
+use std::time::Duration;
+
+/// The units a `HumanDuration` picks from
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum HumanUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    /// 30.436875 days, the mean gregorian month
+    Month,
+    /// 365.2425 days, the mean gregorian year
+    Year,
+}
+
+impl HumanUnit {
+    /// length in seconds
+    fn secs(&self) -> u64 {
+        match self {
+            HumanUnit::Second => 1,
+            HumanUnit::Minute => 60,
+            HumanUnit::Hour => 3600,
+            HumanUnit::Day => 86400,
+            HumanUnit::Week => 604_800,
+            HumanUnit::Month => 2_629_746,
+            HumanUnit::Year => 31_556_952,
+        }
+    }
+}
+
+/// "3 minutes" or "3m", see `HumanDuration`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum HumanStyle {
+    #[default]
+    Long,
+    Short,
+}
+
+/// The words of a language for `HumanDuration`, "{}" in a template is replaced by the number.
+///
+/// Build a table for another language by filling in the fields.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Locale {
+    /// below the `now` threshold, "just now"
+    pub now: &'static str,
+    /// "{} ago"
+    pub past: &'static str,
+    /// "in {}"
+    pub future: &'static str,
+    /// (singular, plural) of each `HumanUnit`, ("{} minute", "{} minutes")
+    pub long: [(&'static str, &'static str); 7],
+    /// each `HumanUnit`, "{}m"
+    pub short: [&'static str; 7],
+}
+
+impl Locale {
+    /// English, "3 minutes ago", "in 2d"
+    pub const EN: Locale = Locale {
+        now: "just now",
+        past: "{} ago",
+        future: "in {}",
+        long: [
+            ("{} second", "{} seconds"),
+            ("{} minute", "{} minutes"),
+            ("{} hour", "{} hours"),
+            ("{} day", "{} days"),
+            ("{} week", "{} weeks"),
+            ("{} month", "{} months"),
+            ("{} year", "{} years"),
+        ],
+        short: ["{}s", "{}m", "{}h", "{}d", "{}w", "{}mo", "{}y"],
+    };
+
+    /// Simplified Chinese, "3分钟前", "2天后"
+    pub const ZH_CN: Locale = Locale {
+        now: "刚刚",
+        past: "{}前",
+        future: "{}后",
+        long: [
+            ("{}秒", "{}秒"),
+            ("{}分钟", "{}分钟"),
+            ("{}小时", "{}小时"),
+            ("{}天", "{}天"),
+            ("{}周", "{}周"),
+            ("{}个月", "{}个月"),
+            ("{}年", "{}年"),
+        ],
+        short: ["{}秒", "{}分", "{}时", "{}天", "{}周", "{}月", "{}年"],
+    };
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::EN
+    }
+}
+
+/// When `HumanDuration` moves on to the next unit, a count of a unit reaching
+/// its threshold is written in the next unit.
+/// The default, 45 seconds, 45 minutes, 22 hours, 26 days and 11 months,
+/// writes 50 minutes as "1 hour" and 25 days as "25 days".
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Thresholds {
+    /// below this many seconds the duration is "just now", 0 never
+    pub now: u64,
+    pub second: u64,
+    pub minute: u64,
+    pub hour: u64,
+    pub day: u64,
+    /// weeks are skipped when 0, only counts of days reaching `day` become weeks
+    pub week: u64,
+    pub month: u64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            now: 1,
+            second: 45,
+            minute: 45,
+            hour: 22,
+            day: 26,
+            week: 0,
+            month: 11,
+        }
+    }
+}
+
+/// Writes a duration for people, in its largest sensible unit, "3 minutes", "in 2 days", "3分钟前".
+/// ```rust
+/// use std::time::Duration;
+/// use fastdate::{HumanDuration, HumanStyle, Locale, SignedDuration};
+/// let v = HumanDuration::new();
+/// assert_eq!(v.format(Duration::from_secs(150)), "3 minutes");
+/// assert_eq!(v.format_relative(SignedDuration::days(-2)), "2 days ago");
+/// let v = v.set_style(HumanStyle::Short);
+/// assert_eq!(v.format_relative(SignedDuration::hours(5)), "in 5h");
+/// let v = HumanDuration::new().set_locale(Locale::ZH_CN);
+/// assert_eq!(v.format_relative(SignedDuration::minutes(-3)), "3分钟前");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct HumanDuration {
+    pub style: HumanStyle,
+    /// how the count of the unit is rounded, `None` truncates, default `HalfUp`
+    pub rounding: Option<RoundMode>,
+    pub thresholds: Thresholds,
+    pub locale: Locale,
+}
+
+impl HumanDuration {
+    /// long English, rounded half up, with the default thresholds
+    pub fn new() -> Self {
+        Self {
+            style: HumanStyle::Long,
+            rounding: Some(RoundMode::HalfUp),
+            thresholds: Thresholds::default(),
+            locale: Locale::EN,
+        }
+    }
+
+    /// set the style, long or short
+    pub fn set_style(mut self, style: HumanStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// set the rounding, `None` truncates
+    pub fn set_rounding(mut self, rounding: Option<RoundMode>) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// set the thresholds
+    pub fn set_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// set the locale
+    pub fn set_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// the count and unit a duration is written in, `None` for "just now"
+    pub fn unit_of(&self, duration: Duration) -> Option<(u64, HumanUnit)> {
+        let nanos = duration.as_nanos() as i128;
+        let t = &self.thresholds;
+        if nanos < t.now as i128 * 1_000_000_000 {
+            return None;
+        }
+        let steps = [
+            (HumanUnit::Second, t.second),
+            (HumanUnit::Minute, t.minute),
+            (HumanUnit::Hour, t.hour),
+            (HumanUnit::Day, t.day),
+            (HumanUnit::Week, t.week),
+            (HumanUnit::Month, t.month),
+        ];
+        for (unit, threshold) in steps {
+            if unit == HumanUnit::Week && threshold == 0 {
+                continue;
+            }
+            // a duration promoted past the threshold of the smaller unit is at least 1,
+            // 50 minutes floored to hours is "1 hour", not "0 hours"
+            let count = self.count(nanos, unit);
+            let count = if unit == HumanUnit::Second {
+                count
+            } else {
+                count.max(1)
+            };
+            if count < threshold {
+                return Some((count, unit));
+            }
+        }
+        Some((self.count(nanos, HumanUnit::Year).max(1), HumanUnit::Year))
+    }
+
+    /// "3 minutes", below the `now` threshold in seconds
+    pub fn format(&self, duration: Duration) -> String {
+        match self.unit_of(duration) {
+            None => {
+                let count = self.count(duration.as_nanos() as i128, HumanUnit::Second);
+                self.write_unit(count, HumanUnit::Second)
+            }
+            Some((count, unit)) => self.write_unit(count, unit),
+        }
+    }
+
+    /// "in 3 minutes" for a positive duration, "3 minutes ago" for a negative one
+    pub fn format_relative(&self, duration: SignedDuration) -> String {
+        let abs = duration.unsigned_abs();
+        match self.unit_of(abs) {
+            None => self.locale.now.to_string(),
+            Some((count, unit)) => {
+                let template = if duration.is_negative() {
+                    self.locale.past
+                } else {
+                    self.locale.future
+                };
+                template.replace("{}", &self.write_unit(count, unit))
+            }
+        }
+    }
+
+    fn count(&self, nanos: i128, unit: HumanUnit) -> u64 {
+        let step = unit.secs() as i128 * 1_000_000_000;
+        let align = match self.rounding {
+            Some(mode) => Align::Round(mode),
+            None => Align::Floor,
+        };
+        let v = align_nanos(
+            nanos,
+            TimeUnit::every(Duration::from_secs(unit.secs())),
+            align,
+        );
+        (v / step) as u64
+    }
+
+    fn write_unit(&self, count: u64, unit: HumanUnit) -> String {
+        let index = unit as usize;
+        let template = match self.style {
+            HumanStyle::Long if count == 1 => self.locale.long[index].0,
+            HumanStyle::Long => self.locale.long[index].1,
+            HumanStyle::Short => self.locale.short[index],
+        };
+        template.replace("{}", &count.to_string())
+    }
+}
+
+impl Default for HumanDuration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,377 @@
+use crate::error::Error;
+use crate::{CalendarUnit, Date, DateTime, IsoDuration};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// A half-open span `[start, end)` of `DateTime` or `Date`, such as a booking or a shift.
+///
+/// Parse using `FromStr` impl, an ISO 8601 interval "start/end", "start/duration" or "duration/end",
+/// the duration is an `IsoDuration` "PnYnMnWnDTnHnMnS", added as `IsoDuration::add_to` does.
+/// Format using the `Display` trait, "start/end".
+/// ```rust
+/// use std::str::FromStr;
+/// use fastdate::{DateTime, Interval};
+/// let v = Interval::<DateTime>::from_str("2022-12-13T09:00:00Z/PT1H30M").unwrap();
+/// assert_eq!(v.to_string(), "2022-12-13T09:00:00Z/2022-12-13T10:30:00Z");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Clone + Ord> Interval<T> {
+    /// new an interval, an error when end is before start
+    pub fn new(start: T, end: T) -> Result<Self, Error> {
+        if end < start {
+            return Err(Error::E("EndBeforeStart".to_string()));
+        }
+        Ok(Self { start, end })
+    }
+
+    /// is start equal to end?
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// is v in `[start, end)`?
+    pub fn contains(&self, v: &T) -> bool {
+        &self.start <= v && v < &self.end
+    }
+
+    /// do the intervals share any time? touching intervals do not
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// the time in both intervals, `None` when they do not overlap
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let start = cmp_max(&self.start, &other.start);
+        let end = cmp_min(&self.end, &other.end);
+        (start < end).then(|| Interval {
+            start: start.clone(),
+            end: end.clone(),
+        })
+    }
+
+    /// the time in either interval, `None` when they neither overlap nor touch
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        (self.start <= other.end && other.start <= self.end).then(|| Interval {
+            start: cmp_min(&self.start, &other.start).clone(),
+            end: cmp_max(&self.end, &other.end).clone(),
+        })
+    }
+
+    /// the time between the intervals, `None` when they overlap or touch
+    pub fn gap(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.end < other.start {
+            Some(Interval {
+                start: self.end.clone(),
+                end: other.start.clone(),
+            })
+        } else if other.end < self.start {
+            Some(Interval {
+                start: other.end.clone(),
+                end: self.start.clone(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+fn cmp_min<'a, T: Ord>(a: &'a T, b: &'a T) -> &'a T {
+    if b < a { b } else { a }
+}
+
+fn cmp_max<'a, T: Ord>(a: &'a T, b: &'a T) -> &'a T {
+    if b > a { b } else { a }
+}
+
+impl Interval<DateTime> {
+    /// length of the interval, leap seconds are not counted
+    pub fn duration(&self) -> Duration {
+        self.end.abs_diff(&self.start)
+    }
+
+    /// cut the interval at the starts of unit, at the offset of start
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use fastdate::{CalendarUnit, DateTime, Interval};
+    /// let v = Interval::<DateTime>::from_str("2022-12-13T22:00:00Z/2022-12-14T06:00:00Z").unwrap();
+    /// assert_eq!(v.split_by(CalendarUnit::Day).len(), 2);
+    /// ```
+    pub fn split_by(&self, unit: CalendarUnit) -> Vec<Interval<DateTime>> {
+        let mut v = Vec::new();
+        let mut start = self.start.clone();
+        while start < self.end {
+            let end = match start.end_of_exclusive(unit) {
+                Ok(next) if next < self.end => next,
+                _ => self.end.clone(),
+            };
+            v.push(Interval {
+                start,
+                end: end.clone(),
+            });
+            start = end;
+        }
+        v
+    }
+}
+
+impl Interval<Date> {
+    /// number of days in the interval
+    pub fn days(&self) -> i64 {
+        self.end.days_since_epoch() - self.start.days_since_epoch()
+    }
+
+    /// length of the interval, 24 hours a day
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs(self.days().max(0) as u64 * 86400)
+    }
+
+    /// cut the interval at the first days of unit
+    pub fn split_by(&self, unit: CalendarUnit) -> Vec<Interval<Date>> {
+        let mut v = Vec::new();
+        let mut start = self.start.clone();
+        while start < self.end {
+            let end = match start.end_of_exclusive(unit) {
+                Ok(next) if next < self.end => next,
+                _ => self.end.clone(),
+            };
+            v.push(Interval {
+                start,
+                end: end.clone(),
+            });
+            start = end;
+        }
+        v
+    }
+}
+
+/// parse "start/end", "start/duration" or "duration/end"
+fn parse_interval<T: Clone + Ord + FromStr<Err = Error>>(
+    s: &str,
+    add: impl Fn(&IsoDuration, &T) -> Result<T, Error>,
+) -> Result<Interval<T>, Error> {
+    let (start, end) = s
+        .split_once('/')
+        .ok_or_else(|| Error::from("MissingIntervalSeparator"))?;
+    match (start.starts_with('P'), end.starts_with('P')) {
+        (false, false) => Interval::new(T::from_str(start)?, T::from_str(end)?),
+        (false, true) => {
+            let start = T::from_str(start)?;
+            let end = add(&IsoDuration::from_str(end)?, &start)?;
+            Interval::new(start, end)
+        }
+        (true, false) => {
+            let end = T::from_str(end)?;
+            let start = add(&IsoDuration::from_str(start)?.negate(), &end)?;
+            Interval::new(start, end)
+        }
+        (true, true) => Err(Error::E("InvalidInterval".to_string())),
+    }
+}
+
+impl FromStr for Interval<DateTime> {
+    type Err = Error;
+
+    /// "2022-12-13T09:00:00Z/2022-12-13T10:00:00Z", "2022-12-13T09:00:00Z/PT1H"
+    /// or "PT1H/2022-12-13T10:00:00Z"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_interval(s, IsoDuration::add_to)
+    }
+}
+
+impl FromStr for Interval<Date> {
+    type Err = Error;
+
+    /// "2022-12-01/2023-01-01", "2022-12-01/P1M" or "P1M/2023-01-01"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_interval(s, IsoDuration::add_to_date)
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.start, self.end)
+    }
+}
+
+impl<T: Display> Serialize for Interval<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Interval<T>
+where
+    Interval<T>: FromStr<Err = Error>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Interval::from_str(&String::deserialize(deserializer)?)
+            .map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
+
+/// Sorted, non-overlapping intervals, overlapping and touching intervals are merged on insert.
+/// ```rust
+/// use std::str::FromStr;
+/// use fastdate::{DateTime, Interval, IntervalSet};
+/// let day = Interval::<DateTime>::from_str("2022-12-13T09:00:00Z/2022-12-13T17:00:00Z").unwrap();
+/// let busy: IntervalSet<DateTime> = [
+///     Interval::from_str("2022-12-13T10:00:00Z/PT1H").unwrap(),
+///     Interval::from_str("2022-12-13T10:30:00Z/PT1H").unwrap(),
+/// ].into_iter().collect();
+/// let free = IntervalSet::from(day).difference(&busy);
+/// assert_eq!(free.intervals().len(), 2);
+/// assert_eq!(free.intervals()[1].to_string(), "2022-12-13T11:30:00Z/2022-12-13T17:00:00Z");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Clone + Ord> IntervalSet<T> {
+    /// an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// the intervals, sorted and non-overlapping
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// is v in any interval?
+    pub fn contains(&self, v: &T) -> bool {
+        self.intervals.iter().any(|i| i.contains(v))
+    }
+
+    /// add an interval, merging it with the intervals it overlaps or touches.
+    /// empty intervals are ignored
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let index = self.intervals.partition_point(|i| i.start < interval.start);
+        self.intervals.insert(index, interval);
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(self.intervals.len());
+        for interval in self.intervals.drain(..) {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => {
+                    if interval.end > last.end {
+                        last.end = interval.end;
+                    }
+                }
+                _ => merged.push(interval),
+            }
+        }
+        self.intervals = merged;
+    }
+
+    /// remove the time of an interval
+    pub fn subtract(&mut self, interval: &Interval<T>) {
+        let mut v = Vec::with_capacity(self.intervals.len() + 1);
+        for i in self.intervals.drain(..) {
+            if !i.overlaps(interval) {
+                v.push(i);
+                continue;
+            }
+            if i.start < interval.start {
+                v.push(Interval {
+                    start: i.start.clone(),
+                    end: interval.start.clone(),
+                });
+            }
+            if interval.end < i.end {
+                v.push(Interval {
+                    start: interval.end.clone(),
+                    end: i.end,
+                });
+            }
+        }
+        self.intervals = v;
+    }
+
+    /// the time in either set
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut v = self.clone();
+        for i in &other.intervals {
+            v.insert(i.clone());
+        }
+        v
+    }
+
+    /// the time in self but not in other, such as free time minus busy time
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut v = self.clone();
+        for i in &other.intervals {
+            v.subtract(i);
+        }
+        v
+    }
+
+    /// the time in both sets
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut v = IntervalSet::new();
+        for a in &self.intervals {
+            for b in &other.intervals {
+                if let Some(i) = a.intersection(b) {
+                    v.insert(i);
+                }
+            }
+        }
+        v
+    }
+
+    /// the time between the intervals
+    pub fn gaps(&self) -> Vec<Interval<T>> {
+        self.intervals
+            .windows(2)
+            .map(|w| Interval {
+                start: w[0].end.clone(),
+                end: w[1].start.clone(),
+            })
+            .collect()
+    }
+}
+
+impl<T: Clone + Ord> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut v = IntervalSet::new();
+        v.insert(interval);
+        v
+    }
+}
+
+impl<T: Clone + Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut v = IntervalSet::new();
+        for interval in iter {
+            v.insert(interval);
+        }
+        v
+    }
+}
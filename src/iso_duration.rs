@@ -0,0 +1,359 @@
+use crate::date::days_in_month;
+use crate::error::Error;
+use crate::{Date, DateTime, SignedDuration, YearMonth};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// An ISO 8601 duration, "P1Y2M10DT2H30M" or "PT0.5S".
+///
+/// Years, months, weeks and days are calendar parts, added to the calendar date,
+/// a month after Jan 31 is the last day of February.
+/// Hours, minutes, seconds and nanos are exact.
+///
+/// Parse using `FromStr` impl, "-P1D" is negative.
+/// Format using the `Display` trait, the zero duration is "PT0S".
+/// ```rust
+/// use std::str::FromStr;
+/// use fastdate::{DateTime, IsoDuration};
+/// let d = IsoDuration::from_str("P1M1DT1H").unwrap();
+/// let v = DateTime::from_str("2023-01-31T09:00:00Z").unwrap() + d;
+/// assert_eq!(v.to_string(), "2023-03-01T10:00:00Z");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct IsoDuration {
+    /// the whole duration is negative, "-P1D"
+    pub negative: bool,
+    pub years: u64,
+    pub months: u64,
+    pub weeks: u64,
+    pub days: u64,
+    pub hours: u64,
+    pub minutes: u64,
+    pub seconds: u64,
+    /// 0...999999999, more carries into the seconds
+    pub nanos: u32,
+}
+
+impl IsoDuration {
+    /// is every part zero?
+    pub fn is_zero(&self) -> bool {
+        !self.has_calendar_parts() && self.exact_nanos() == 0
+    }
+
+    /// are there years, months, weeks or days?
+    pub fn has_calendar_parts(&self) -> bool {
+        self.years != 0 || self.months != 0 || self.weeks != 0 || self.days != 0
+    }
+
+    /// the duration with the sign flipped
+    pub fn negate(mut self) -> Self {
+        self.negative = !self.negative;
+        self
+    }
+
+    /// add to a DateTime, the calendar parts at its offset, then the exact parts,
+    /// an error when out of range
+    pub fn add_to(&self, datetime: &DateTime) -> Result<DateTime, Error> {
+        let mut dt = datetime.clone();
+        let sign = self.sign();
+        let months = self.total_months() * sign;
+        if months != 0 {
+            let date = add_months(&Date::from(dt.clone()), months)?;
+            dt = dt.with_date(date)?;
+        }
+        let nanos = (self.total_days() * 86_400_000_000_000 + self.exact_nanos()) * sign;
+        let secs =
+            i64::try_from(nanos / 1_000_000_000).map_err(|_| Error::from("OutOfRangeDate"))?;
+        let d = SignedDuration::new(secs, (nanos % 1_000_000_000) as i32);
+        dt.checked_add_signed(d)
+            .ok_or_else(|| Error::from("OutOfRangeDate"))
+    }
+
+    /// sub from a DateTime, same as adding the negated duration
+    pub fn sub_from(&self, datetime: &DateTime) -> Result<DateTime, Error> {
+        self.negate().add_to(datetime)
+    }
+
+    /// add to a Date, an error for hours, minutes or seconds, or when out of range
+    pub fn add_to_date(&self, date: &Date) -> Result<Date, Error> {
+        if self.exact_nanos() != 0 {
+            return Err(Error::E("InvalidDuration".to_string()));
+        }
+        let sign = self.sign();
+        let date = add_months(date, self.total_months() * sign)?;
+        let days =
+            i64::try_from(self.total_days() * sign).map_err(|_| Error::from("OutOfRangeDate"))?;
+        Date::try_from_days_since_epoch(date.days_since_epoch().saturating_add(days))
+    }
+
+    /// sub from a Date, same as adding the negated duration
+    pub fn sub_from_date(&self, date: &Date) -> Result<Date, Error> {
+        self.negate().add_to_date(date)
+    }
+
+    fn sign(&self) -> i128 {
+        if self.negative { -1 } else { 1 }
+    }
+
+    fn total_months(&self) -> i128 {
+        self.years as i128 * 12 + self.months as i128
+    }
+
+    fn total_days(&self) -> i128 {
+        self.weeks as i128 * 7 + self.days as i128
+    }
+
+    /// hours, minutes, seconds and nanos in nanoseconds, unsigned
+    fn exact_nanos(&self) -> i128 {
+        (self.hours as i128 * 3600 + self.minutes as i128 * 60 + self.seconds as i128)
+            * 1_000_000_000
+            + self.nanos as i128
+    }
+}
+
+/// add months to a date, the day is clamped to the end of a shorter month
+fn add_months(date: &Date, months: i128) -> Result<Date, Error> {
+    let months = i32::try_from(months).map_err(|_| Error::from("OutOfRangeDate"))?;
+    let v = YearMonth::from(date.clone()).add_months(months)?;
+    Ok(Date {
+        day: date.day.min(days_in_month(v.year, v.mon)),
+        mon: v.mon,
+        year: v.year,
+    })
+}
+
+impl FromStr for IsoDuration {
+    type Err = Error;
+
+    /// "PnYnMnWnDTnHnMnS", designators in this order, each at most once,
+    /// only the seconds may have a fraction, up to 9 digits, "-P1D" is negative
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let bytes = s.as_bytes();
+        if bytes.first() != Some(&b'P') {
+            return Err(Error::E("InvalidDuration".to_string()));
+        }
+        let mut v = IsoDuration {
+            negative,
+            ..Default::default()
+        };
+        // designators must come in this order, each at most once
+        const ORDER: [(bool, u8); 7] = [
+            (false, b'Y'),
+            (false, b'M'),
+            (false, b'W'),
+            (false, b'D'),
+            (true, b'H'),
+            (true, b'M'),
+            (true, b'S'),
+        ];
+        let mut next = 0;
+        let mut time = false;
+        let mut index = 1;
+        while index < bytes.len() {
+            if bytes[index] == b'T' && !time {
+                time = true;
+                next = next.max(4);
+                index += 1;
+                if index == bytes.len() {
+                    return Err(Error::E("InvalidDuration".to_string()));
+                }
+                continue;
+            }
+            let start = index;
+            while index < bytes.len() && (bytes[index].is_ascii_digit() || bytes[index] == b'.') {
+                index += 1;
+            }
+            let number = &s[start..index];
+            let designator = bytes.get(index).copied();
+            index += 1;
+            let rank = ORDER[next..]
+                .iter()
+                .position(|v| *v == (time, designator.unwrap_or_default()))
+                .ok_or_else(|| Error::from("InvalidDuration"))?
+                + next;
+            next = rank + 1;
+            let (int, frac) = number.split_once('.').unwrap_or((number, ""));
+            if int.is_empty() || int.len() > 18 || frac.len() > 9 || (rank != 6 && number != int) {
+                return Err(Error::E("InvalidDuration".to_string()));
+            }
+            let n: u64 = int.parse().map_err(|_| Error::from("InvalidDuration"))?;
+            match rank {
+                0 => v.years = n,
+                1 => v.months = n,
+                2 => v.weeks = n,
+                3 => v.days = n,
+                4 => v.hours = n,
+                5 => v.minutes = n,
+                _ => {
+                    v.seconds = n;
+                    v.nanos = format!("{:0<9}", frac)
+                        .parse()
+                        .map_err(|_| Error::from("InvalidDuration"))?;
+                }
+            }
+        }
+        if next == 0 {
+            return Err(Error::E("InvalidDuration".to_string()));
+        }
+        Ok(v)
+    }
+}
+
+impl Display for IsoDuration {
+    /// "P1Y2M10DT2H30M", "PT0.5S", "-P1D" or "PT0S"
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        f.write_str("P")?;
+        for (v, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if v != 0 {
+                write!(f, "{}{}", v, designator)?;
+            }
+        }
+        if self.exact_nanos() == 0 {
+            if !self.has_calendar_parts() {
+                f.write_str("T0S")?;
+            }
+            return Ok(());
+        }
+        f.write_str("T")?;
+        if self.hours != 0 {
+            write!(f, "{}H", self.hours)?;
+        }
+        if self.minutes != 0 {
+            write!(f, "{}M", self.minutes)?;
+        }
+        if self.seconds != 0 || self.nanos != 0 {
+            let nanos = self.nanos % 1_000_000_000;
+            write!(
+                f,
+                "{}",
+                self.seconds as u128 + (self.nanos / 1_000_000_000) as u128
+            )?;
+            if nanos != 0 {
+                let frac = format!("{:09}", nanos);
+                write!(f, ".{}", frac.trim_end_matches('0'))?;
+            }
+            f.write_str("S")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Duration> for IsoDuration {
+    /// "PT1H30M", as hours, minutes and seconds
+    fn from(d: Duration) -> Self {
+        let secs = d.as_secs();
+        Self {
+            hours: secs / 3600,
+            minutes: secs / 60 % 60,
+            seconds: secs % 60,
+            nanos: d.subsec_nanos(),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<IsoDuration> for Duration {
+    type Error = Error;
+
+    /// an error for calendar parts, a negative duration, or an overflow
+    fn try_from(v: IsoDuration) -> Result<Self, Self::Error> {
+        if v.has_calendar_parts() {
+            return Err(Error::E("CalendarDuration".to_string()));
+        }
+        if v.negative && !v.is_zero() {
+            return Err(Error::E("NegativeDuration".to_string()));
+        }
+        let secs = u64::try_from(v.exact_nanos() / 1_000_000_000)
+            .map_err(|_| Error::from("OutOfRangeDuration"))?;
+        let nanos = (v.exact_nanos() % 1_000_000_000) as u32;
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+impl TryFrom<IsoDuration> for SignedDuration {
+    type Error = Error;
+
+    /// an error for calendar parts, or an overflow
+    fn try_from(v: IsoDuration) -> Result<Self, Self::Error> {
+        if v.has_calendar_parts() {
+            return Err(Error::E("CalendarDuration".to_string()));
+        }
+        let secs = i64::try_from(v.exact_nanos() / 1_000_000_000)
+            .map_err(|_| Error::from("OutOfRangeDuration"))?;
+        let nanos = (v.exact_nanos() % 1_000_000_000) as i32;
+        let d = SignedDuration::new(secs, nanos);
+        Ok(if v.negative { -d } else { d })
+    }
+}
+
+impl Add<IsoDuration> for DateTime {
+    type Output = DateTime;
+
+    /// panics when out of range, see `IsoDuration::add_to`
+    fn add(self, rhs: IsoDuration) -> Self::Output {
+        rhs.add_to(&self).expect("overflow adding IsoDuration")
+    }
+}
+
+impl Sub<IsoDuration> for DateTime {
+    type Output = DateTime;
+
+    /// panics when out of range, see `IsoDuration::sub_from`
+    fn sub(self, rhs: IsoDuration) -> Self::Output {
+        rhs.sub_from(&self)
+            .expect("overflow subtracting IsoDuration")
+    }
+}
+
+impl Add<&IsoDuration> for DateTime {
+    type Output = DateTime;
+
+    fn add(self, rhs: &IsoDuration) -> Self::Output {
+        rhs.add_to(&self).expect("overflow adding IsoDuration")
+    }
+}
+
+impl Sub<&IsoDuration> for DateTime {
+    type Output = DateTime;
+
+    fn sub(self, rhs: &IsoDuration) -> Self::Output {
+        rhs.sub_from(&self)
+            .expect("overflow subtracting IsoDuration")
+    }
+}
+
+impl Serialize for IsoDuration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IsoDuration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        IsoDuration::from_str(&String::deserialize(deserializer)?)
+            .map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
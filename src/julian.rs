@@ -0,0 +1,288 @@
+use crate::Date;
+use crate::date::{parse_ymd, write_year};
+use crate::error::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Date in the proleptic Julian calendar, every fourth year is a leap year.
+///
+/// Parse using `FromStr` impl.
+/// Format using the `Display` trait.
+/// Convert into/from the Gregorian `Date` to use.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct JulianDate {
+    /// 1...31
+    pub(crate) day: u8,
+    /// 1...12
+    pub(crate) mon: u8,
+    /// -999999...999999
+    pub(crate) year: i32,
+}
+
+impl JulianDate {
+    /// new a julian date, checks that year/mon/day is a valid julian calendar date
+    /// ```rust
+    /// assert!(fastdate::JulianDate::new(1500, 2, 29).is_ok());
+    /// assert!(fastdate::JulianDate::new(1501, 2, 29).is_err());
+    /// ```
+    pub fn new(year: i32, mon: u8, day: u8) -> Result<Self, Error> {
+        let date = Self { day, mon, year };
+        date.check()?;
+        Ok(date)
+    }
+
+    /// is year/mon/day a valid julian calendar date?
+    /// always true, the fields are private and every constructor checks them
+    pub fn is_valid(&self) -> bool {
+        self.check().is_ok()
+    }
+
+    /// get day
+    pub fn get_day(&self) -> u8 {
+        self.day
+    }
+
+    /// get mon
+    pub fn get_mon(&self) -> u8 {
+        self.mon
+    }
+
+    /// get year
+    pub fn get_year(&self) -> i32 {
+        self.year
+    }
+
+    fn check(&self) -> Result<(), Error> {
+        if !(Date::MIN.year..=Date::MAX.year).contains(&self.year) {
+            return Err(Error::E("OutOfRangeYear".to_string()));
+        }
+        if !(1..=12).contains(&self.mon) {
+            return Err(Error::E("OutOfRangeMonth".to_string()));
+        }
+        if self.day < 1 || self.day > julian_days_in_month(self.year, self.mon) {
+            return Err(Error::E("OutOfRangeDay".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Julian Day Number, the julian day starting at noon (UTC) of this date
+    pub fn to_julian_day(&self) -> i64 {
+        let a = (14 - self.mon as i64) / 12;
+        let y = self.year as i64 + 4800 - a;
+        let m = self.mon as i64 + 12 * a - 3;
+        self.day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083
+    }
+
+    /// julian date from a Julian Day Number
+    pub fn from_julian_day(jdn: i64) -> Result<Self, Error> {
+        if jdn.unsigned_abs() > 1_000_000_000 {
+            return Err(Error::E("OutOfRangeYear".to_string()));
+        }
+        let c = jdn + 32082;
+        let d = (4 * c + 3).div_euclid(1461);
+        let e = c - (1461 * d).div_euclid(4);
+        let m = (5 * e + 2) / 153;
+        let day = (e - (153 * m + 2) / 5 + 1) as u8;
+        let mon = (m + 3 - 12 * (m / 10)) as u8;
+        let year = d - 4800 + m / 10;
+        if year < Date::MIN.year as i64 || year > Date::MAX.year as i64 {
+            return Err(Error::E("OutOfRangeYear".to_string()));
+        }
+        Ok(Self {
+            day,
+            mon,
+            year: year as i32,
+        })
+    }
+
+    /// get week_day, 1(Monday)...7(Sunday)
+    pub fn week_day(&self) -> u8 {
+        (self.to_julian_day().rem_euclid(7) + 1) as u8
+    }
+}
+
+/// number of days in the month of the julian calendar
+fn julian_days_in_month(year: i32, mon: u8) -> u8 {
+    match mon {
+        2 if year.rem_euclid(4) == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => 0,
+    }
+}
+
+impl FromStr for JulianDate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, mon, day) = parse_ymd(s.as_bytes())?;
+        Self::new(year, mon, day)
+    }
+}
+
+impl Display for JulianDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_year(f, self.year)?;
+        write!(f, "-{:02}-{:02}", self.mon, self.day)
+    }
+}
+
+impl Serialize for JulianDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for JulianDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        JulianDate::from_str(&String::deserialize(deserializer)?)
+            .map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
+
+impl From<Date> for JulianDate {
+    fn from(arg: Date) -> Self {
+        // the julian calendar is about 20 years ahead at Date::MIN and behind at Date::MAX,
+        // so every Date is in range
+        JulianDate::from_julian_day(arg.to_julian_day()).unwrap()
+    }
+}
+
+/// fails for julian dates before Date::MIN
+impl TryFrom<JulianDate> for Date {
+    type Error = Error;
+
+    fn try_from(arg: JulianDate) -> Result<Self, Self::Error> {
+        Date::from_julian_day(arg.to_julian_day())
+    }
+}
+
+/// The day a historical calendar switched from the Julian to the Gregorian calendar.
+///
+/// Dates before the cutover are read and written in the Julian calendar,
+/// dates from the cutover on in the Gregorian calendar,
+/// the days skipped by the switch do not exist.
+/// ```rust
+/// let cutover = fastdate::Cutover::BRITAIN;
+/// let d = cutover.parse("1752-09-02").unwrap();
+/// assert_eq!(d.to_string(), "1752-09-13");
+/// assert_eq!(cutover.format(&d), "1752-09-02");
+/// assert_eq!(cutover.format(&fastdate::Date::new(1752, 9, 14).unwrap()), "1752-09-14");
+/// assert!(cutover.parse("1752-09-03").is_err());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Cutover {
+    /// the first day of the Gregorian calendar
+    pub first_gregorian: Date,
+}
+
+impl Default for Cutover {
+    /// `Cutover::ROME`
+    fn default() -> Self {
+        Self::ROME
+    }
+}
+
+impl Cutover {
+    /// 1582-10-15, the original cutover, 1582-10-04 (julian) is followed by 1582-10-15
+    pub const ROME: Cutover = Cutover {
+        first_gregorian: Date {
+            day: 15,
+            mon: 10,
+            year: 1582,
+        },
+    };
+
+    /// 1752-09-14, Great Britain and its colonies, 1752-09-02 (julian) is followed by 1752-09-14
+    pub const BRITAIN: Cutover = Cutover {
+        first_gregorian: Date {
+            day: 14,
+            mon: 9,
+            year: 1752,
+        },
+    };
+
+    /// a cutover at the first Gregorian day
+    pub fn new(first_gregorian: Date) -> Self {
+        Self { first_gregorian }
+    }
+
+    /// is date (Gregorian) on or after the cutover?
+    pub fn is_gregorian(&self, date: &Date) -> bool {
+        date.days_since_epoch() >= self.first_gregorian.days_since_epoch()
+    }
+
+    /// date as the historical calendar shows it
+    pub fn historical(&self, date: &Date) -> HistoricalDate {
+        if self.is_gregorian(date) {
+            HistoricalDate::Gregorian(date.clone())
+        } else {
+            HistoricalDate::Julian(JulianDate::from(date.clone()))
+        }
+    }
+
+    /// date (Gregorian) of a year/mon/day in the historical calendar,
+    /// an error for the days skipped by the cutover
+    pub fn to_date(&self, year: i32, mon: u8, day: u8) -> Result<Date, Error> {
+        let first = &self.first_gregorian;
+        if (year, mon, day) >= (first.year, first.mon, first.day) {
+            return Date::new(year, mon, day);
+        }
+        let date = Date::try_from(JulianDate::new(year, mon, day)?)?;
+        if self.is_gregorian(&date) {
+            return Err(Error::E("SkippedByCutover".to_string()));
+        }
+        Ok(date)
+    }
+
+    /// parse a "YYYY-MM-DD" of the historical calendar into a date (Gregorian)
+    pub fn parse(&self, arg: &str) -> Result<Date, Error> {
+        let (year, mon, day) = parse_ymd(arg.as_bytes())?;
+        self.to_date(year, mon, day)
+    }
+
+    /// format a date (Gregorian) as "YYYY-MM-DD" of the historical calendar
+    pub fn format(&self, date: &Date) -> String {
+        self.historical(date).to_string()
+    }
+}
+
+/// A date of a historical calendar, see `Cutover`
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HistoricalDate {
+    Julian(JulianDate),
+    Gregorian(Date),
+}
+
+impl HistoricalDate {
+    /// Julian Day Number
+    pub fn to_julian_day(&self) -> i64 {
+        match self {
+            HistoricalDate::Julian(v) => v.to_julian_day(),
+            HistoricalDate::Gregorian(v) => v.to_julian_day(),
+        }
+    }
+
+    /// get week_day, 1(Monday)...7(Sunday)
+    pub fn week_day(&self) -> u8 {
+        (self.to_julian_day().rem_euclid(7) + 1) as u8
+    }
+}
+
+impl Display for HistoricalDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoricalDate::Julian(v) => v.fmt(f),
+            HistoricalDate::Gregorian(v) => v.fmt(f),
+        }
+    }
+}
@@ -3,16 +3,37 @@
 pub extern crate time1;
 
 pub mod error;
+pub mod serde;
 pub mod sys;
 
+mod business;
+mod cron;
 mod date;
 mod datetime;
+mod humanize;
+mod interval;
+mod iso_duration;
+mod julian;
+mod offset_time;
+mod partial;
+mod rrule;
 mod time;
+mod time_range;
 
+pub use business::*;
+pub use cron::*;
 pub use date::*;
 pub use datetime::*;
+pub use humanize::*;
+pub use interval::*;
+pub use iso_duration::*;
+pub use julian::*;
+pub use offset_time::*;
+pub use partial::*;
+pub use rrule::*;
 use std::time::Duration;
 pub use time::*;
+pub use time_range::*;
 
 // get a character from the bytes as as a decimal
 macro_rules! get_digit {
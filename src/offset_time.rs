@@ -0,0 +1,197 @@
+use crate::error::Error;
+use crate::get_digit;
+use crate::time::NANOS_PER_DAY;
+use crate::{Date, DateTime, Time};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A time of day with an offset but no date, SQL `TIME WITH TIME ZONE`.
+///
+/// Parse using `FromStr` impl, "11:12:14+08:00" or "11:12:14.5Z".
+/// Format using the `Display` trait.
+/// Compares by the instant of day in UTC, then by offset.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct OffsetTime {
+    pub(crate) time: Time,
+    /// offset in seconds, -86399...86399
+    pub(crate) offset: i32,
+}
+
+impl OffsetTime {
+    /// new an offset time, checks that time and offset are in range
+    pub fn new(time: Time, offset_sec: i32) -> Result<Self, Error> {
+        time.check()?;
+        if !(-86399..=86399).contains(&offset_sec) {
+            return Err(Error::E("OutOfRangeOffset".to_string()));
+        }
+        Ok(Self {
+            time,
+            offset: offset_sec,
+        })
+    }
+
+    /// get time
+    pub fn get_time(&self) -> &Time {
+        &self.time
+    }
+
+    /// get offset in seconds
+    pub fn get_offset(&self) -> i32 {
+        self.offset
+    }
+
+    /// the same instant in UTC, with the days carried over midnight (-1, 0 or 1)
+    /// ```rust
+    /// use std::str::FromStr;
+    /// let t = fastdate::OffsetTime::from_str("07:00:00+08:00").unwrap();
+    /// let (utc, days) = t.to_utc();
+    /// assert_eq!((utc.to_string().as_str(), days), ("23:00:00", -1));
+    /// ```
+    pub fn to_utc(&self) -> (Time, i64) {
+        self.convert(0)
+    }
+
+    /// the same instant at another offset (clamped to -86399...86399),
+    /// with the days carried over midnight
+    pub fn to_offset(&self, offset_sec: i32) -> (OffsetTime, i64) {
+        let offset_sec = offset_sec.clamp(-86399, 86399);
+        let (time, days) = self.convert(offset_sec);
+        (
+            Self {
+                time,
+                offset: offset_sec,
+            },
+            days,
+        )
+    }
+
+    fn convert(&self, offset_sec: i32) -> (Time, i64) {
+        let leap = self.time.is_leap_second();
+        let nanos = self.time.nanos_of_day() as i64 - if leap { 1_000_000_000 } else { 0 }
+            + (offset_sec as i64 - self.offset as i64) * 1_000_000_000;
+        let mut time = Time::from_nanos_of_day(nanos.rem_euclid(NANOS_PER_DAY as i64) as u64);
+        // keep a leap second, while it is still second 59
+        if leap && time.sec == 59 {
+            time.nano += 1_000_000_000;
+        }
+        (time, nanos.div_euclid(NANOS_PER_DAY as i64))
+    }
+
+    /// combine with a date into a DateTime at this offset
+    pub fn with_date(&self, date: Date) -> Result<DateTime, Error> {
+        DateTime::new(date, self.time.clone(), self.offset)
+    }
+
+    /// UTC nanoseconds since midnight, may be negative or past one day
+    fn instant_of_day(&self) -> i64 {
+        self.time.nanos_of_day() as i64 - self.offset as i64 * 1_000_000_000
+    }
+}
+
+/// parse an offset "Z", "+08:00", "+0800", "+08" or "+08:00:30" into seconds
+fn parse_offset(bytes: &[u8]) -> Result<i32, Error> {
+    if bytes == b"Z" || bytes == b"z" {
+        return Ok(0);
+    }
+    let sign = match bytes.first() {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Err(Error::E("InvalidCharOffset".to_string())),
+    };
+    let h1 = get_digit!(bytes, 1, "InvalidCharOffset") as i32;
+    let h2 = get_digit!(bytes, 2, "InvalidCharOffset") as i32;
+    let mut secs = (h1 * 10 + h2) * 3600;
+    let mut index = 3;
+    for unit in [60, 1] {
+        if index == bytes.len() {
+            break;
+        }
+        if bytes[index] == b':' {
+            index += 1;
+        }
+        let v1 = get_digit!(bytes, index, "InvalidCharOffset") as i32;
+        let v2 = get_digit!(bytes, index + 1, "InvalidCharOffset") as i32;
+        if v1 * 10 + v2 > 59 {
+            return Err(Error::E("OutOfRangeOffset".to_string()));
+        }
+        secs += (v1 * 10 + v2) * unit;
+        index += 2;
+    }
+    if index != bytes.len() {
+        return Err(Error::E("TooLong".to_string()));
+    }
+    Ok(sign * secs)
+}
+
+impl From<DateTime> for OffsetTime {
+    fn from(arg: DateTime) -> Self {
+        Self {
+            offset: arg.offset(),
+            time: Time::from(arg),
+        }
+    }
+}
+
+impl Ord for OffsetTime {
+    fn cmp(&self, other: &OffsetTime) -> cmp::Ordering {
+        (self.instant_of_day(), self.offset).cmp(&(other.instant_of_day(), other.offset))
+    }
+}
+
+impl PartialOrd for OffsetTime {
+    fn partial_cmp(&self, other: &OffsetTime) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for OffsetTime {
+    type Err = Error;
+
+    /// "11:12:14+08:00", "11:12:14.123456-03:30", "11:12Z" or "111214+0800"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let index = s
+            .rfind(['+', '-', 'Z', 'z'])
+            .ok_or_else(|| Error::from("MissingOffset"))?;
+        let time = Time::from_str(&s[..index])?;
+        let offset = parse_offset(&s.as_bytes()[index..])?;
+        Self::new(time, offset)
+    }
+}
+
+impl Display for OffsetTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.time.fmt(f)?;
+        if self.offset == 0 {
+            return f.write_str("Z");
+        }
+        let sign = if self.offset > 0 { '+' } else { '-' };
+        let v = self.offset.unsigned_abs();
+        write!(f, "{}{:02}:{:02}", sign, v / 3600, v / 60 % 60)?;
+        if !v.is_multiple_of(60) {
+            write!(f, ":{:02}", v % 60)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for OffsetTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OffsetTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        OffsetTime::from_str(&String::deserialize(deserializer)?)
+            .map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
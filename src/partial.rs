@@ -0,0 +1,301 @@
+use crate::date::{days_in_month, is_leap_year, parse_expanded_year, write_year};
+use crate::error::Error;
+use crate::{Date, get_digit};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A month of a year, such as a credit card expiry or a billing period.
+///
+/// Parse using `FromStr` impl, "2022-12".
+/// Format using the `Display` trait.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct YearMonth {
+    /// 1...12
+    pub(crate) mon: u8,
+    /// -999999...999999
+    pub(crate) year: i32,
+}
+
+impl YearMonth {
+    /// new a year month, checks that year and mon are in range
+    pub fn new(year: i32, mon: u8) -> Result<Self, Error> {
+        Date::new(year, mon, 1)?;
+        Ok(Self { mon, year })
+    }
+
+    /// get mon
+    pub fn get_mon(&self) -> u8 {
+        self.mon
+    }
+
+    /// get year
+    pub fn get_year(&self) -> i32 {
+        self.year
+    }
+
+    /// number of days in the month
+    pub fn days_in_month(&self) -> u8 {
+        days_in_month(self.year, self.mon)
+    }
+
+    /// the date of a day of this month
+    pub fn day(&self, day: u8) -> Result<Date, Error> {
+        Date::new(self.year, self.mon, day)
+    }
+
+    /// the first day of this month
+    pub fn first_day(&self) -> Date {
+        Date {
+            day: 1,
+            mon: self.mon,
+            year: self.year,
+        }
+    }
+
+    /// the last day of this month
+    pub fn last_day(&self) -> Date {
+        Date {
+            day: self.days_in_month(),
+            mon: self.mon,
+            year: self.year,
+        }
+    }
+
+    /// every day of this month
+    /// ```rust
+    /// let v = fastdate::YearMonth::new(2024, 2).unwrap();
+    /// assert_eq!(v.days().count(), 29);
+    /// ```
+    pub fn days(&self) -> impl Iterator<Item = Date> + use<> {
+        let (mon, year) = (self.mon, self.year);
+        (1..=self.days_in_month()).map(move |day| Date { day, mon, year })
+    }
+
+    /// add (or sub, when negative) months, or an error when out of range
+    pub fn add_months(&self, months: i32) -> Result<Self, Error> {
+        let v = self.year as i64 * 12 + (self.mon as i64 - 1) + months as i64;
+        let year =
+            i32::try_from(v.div_euclid(12)).map_err(|_| Error::E("OutOfRangeYear".to_string()))?;
+        Self::new(year, (v.rem_euclid(12) + 1) as u8)
+    }
+}
+
+impl From<Date> for YearMonth {
+    fn from(arg: Date) -> Self {
+        Self {
+            mon: arg.mon,
+            year: arg.year,
+        }
+    }
+}
+
+impl Ord for YearMonth {
+    fn cmp(&self, other: &YearMonth) -> cmp::Ordering {
+        (self.year, self.mon).cmp(&(other.year, other.mon))
+    }
+}
+
+impl PartialOrd for YearMonth {
+    fn partial_cmp(&self, other: &YearMonth) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for YearMonth {
+    type Err = Error;
+
+    /// "2022-12", "+012345-01" or "-0044-03"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        let (year, start) = match bytes.first() {
+            Some(b'+' | b'-') => parse_expanded_year(bytes)?,
+            _ => {
+                let y1 = get_digit!(bytes, 0, "InvalidCharYear") as i32;
+                let y2 = get_digit!(bytes, 1, "InvalidCharYear") as i32;
+                let y3 = get_digit!(bytes, 2, "InvalidCharYear") as i32;
+                let y4 = get_digit!(bytes, 3, "InvalidCharYear") as i32;
+                (y1 * 1000 + y2 * 100 + y3 * 10 + y4, 4)
+            }
+        };
+        if bytes.get(start) != Some(&b'-') {
+            return Err(Error::E("InvalidCharMonth".to_string()));
+        }
+        let m1 = get_digit!(bytes, start + 1, "InvalidCharMonth");
+        let m2 = get_digit!(bytes, start + 2, "InvalidCharMonth");
+        if bytes.len() != start + 3 {
+            return Err(Error::E("TooLong".to_string()));
+        }
+        Self::new(year, m1 * 10 + m2)
+    }
+}
+
+impl Display for YearMonth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_year(f, self.year)?;
+        write!(f, "-{:02}", self.mon)
+    }
+}
+
+impl Serialize for YearMonth {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for YearMonth {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        YearMonth::from_str(&String::deserialize(deserializer)?)
+            .map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
+
+/// Where a Feb 29 `MonthDay` falls in a year that is not a leap year.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum Feb29Policy {
+    /// no occurrence, only leap years have one
+    #[default]
+    Skip,
+    /// Feb 28
+    Feb28,
+    /// Mar 1
+    Mar1,
+}
+
+/// A day of a month in any year, such as a birthday or an anniversary.
+///
+/// Parse using `FromStr` impl, "--12-13".
+/// Format using the `Display` trait.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MonthDay {
+    /// 1...31
+    pub(crate) day: u8,
+    /// 1...12
+    pub(crate) mon: u8,
+}
+
+impl MonthDay {
+    /// new a month day, checks that day exists in mon of a leap year
+    pub fn new(mon: u8, day: u8) -> Result<Self, Error> {
+        Date::new(2000, mon, day)?;
+        Ok(Self { day, mon })
+    }
+
+    /// get day
+    pub fn get_day(&self) -> u8 {
+        self.day
+    }
+
+    /// get mon
+    pub fn get_mon(&self) -> u8 {
+        self.mon
+    }
+
+    /// the date of this month day in a year,
+    /// `None` for Feb 29 in a year that is not a leap year with `Feb29Policy::Skip`
+    pub fn to_date(&self, year: i32, policy: Feb29Policy) -> Option<Date> {
+        if self.mon == 2 && self.day == 29 && !is_leap_year(year) {
+            return match policy {
+                Feb29Policy::Skip => None,
+                Feb29Policy::Feb28 => Date::new(year, 2, 28).ok(),
+                Feb29Policy::Mar1 => Date::new(year, 3, 1).ok(),
+            };
+        }
+        Date::new(year, self.mon, self.day).ok()
+    }
+
+    /// the first occurrence strictly after a date, `None` only past `Date::MAX`
+    /// ```rust
+    /// use std::str::FromStr;
+    /// let birthday = fastdate::MonthDay::from_str("--02-29").unwrap();
+    /// let today = fastdate::Date::new(2022, 12, 13).unwrap();
+    /// let next = birthday.next_occurrence(&today, fastdate::Feb29Policy::Mar1).unwrap();
+    /// assert_eq!(next.to_string(), "2023-03-01");
+    /// ```
+    pub fn next_occurrence(&self, after: &Date, policy: Feb29Policy) -> Option<Date> {
+        // a leap year is at most 8 years away
+        for year in after.year..=after.year.saturating_add(8) {
+            if let Some(date) = self.to_date(year, policy)
+                && date.days_since_epoch() > after.days_since_epoch()
+            {
+                return Some(date);
+            }
+        }
+        None
+    }
+}
+
+impl From<Date> for MonthDay {
+    fn from(arg: Date) -> Self {
+        Self {
+            day: arg.day,
+            mon: arg.mon,
+        }
+    }
+}
+
+impl Ord for MonthDay {
+    fn cmp(&self, other: &MonthDay) -> cmp::Ordering {
+        (self.mon, self.day).cmp(&(other.mon, other.day))
+    }
+}
+
+impl PartialOrd for MonthDay {
+    fn partial_cmp(&self, other: &MonthDay) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for MonthDay {
+    type Err = Error;
+
+    /// "--12-13"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 7 {
+            return Err(Error::E("InvalidLength".to_string()));
+        }
+        if !bytes.starts_with(b"--") || bytes[4] != b'-' {
+            return Err(Error::E("InvalidChar".to_string()));
+        }
+        let m1 = get_digit!(bytes, 2, "InvalidCharMonth");
+        let m2 = get_digit!(bytes, 3, "InvalidCharMonth");
+        let d1 = get_digit!(bytes, 5, "InvalidCharDay");
+        let d2 = get_digit!(bytes, 6, "InvalidCharDay");
+        Self::new(m1 * 10 + m2, d1 * 10 + d2)
+    }
+}
+
+impl Display for MonthDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "--{:02}-{:02}", self.mon, self.day)
+    }
+}
+
+impl Serialize for MonthDay {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MonthDay {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        MonthDay::from_str(&String::deserialize(deserializer)?)
+            .map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
@@ -0,0 +1,963 @@
+use crate::date::{days_in_month, is_leap_year};
+use crate::error::Error;
+use crate::{Date, DateTime, Time};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// periods in a row without an occurrence after which a rule is taken to never occur,
+/// above the 14609 days between two Feb 29 on the same week day
+const MAX_EMPTY_PERIODS: u32 = 20_000;
+
+const WEEK_DAYS: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+/// The FREQ of a recurrence rule
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl FromStr for Frequency {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SECONDLY" => Frequency::Secondly,
+            "MINUTELY" => Frequency::Minutely,
+            "HOURLY" => Frequency::Hourly,
+            "DAILY" => Frequency::Daily,
+            "WEEKLY" => Frequency::Weekly,
+            "MONTHLY" => Frequency::Monthly,
+            "YEARLY" => Frequency::Yearly,
+            _ => return Err(Error::from(format!("InvalidFreq '{}'", s))),
+        })
+    }
+}
+
+impl Display for Frequency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Frequency::Secondly => "SECONDLY",
+            Frequency::Minutely => "MINUTELY",
+            Frequency::Hourly => "HOURLY",
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        })
+    }
+}
+
+/// A BYDAY entry, "MO" is every Monday, "-1FR" the last Friday of the month (or year)
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct WeekdayNum {
+    /// 0 for every week_day, 1...53 counts from the start, -1...-53 from the end
+    pub n: i8,
+    /// 1(Monday)...7(Sunday)
+    pub week_day: u8,
+}
+
+impl FromStr for WeekdayNum {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() < 2 || !s.is_char_boundary(s.len() - 2) {
+            return Err(Error::from(format!("InvalidByDay '{}'", s)));
+        }
+        let (n, day) = s.split_at(s.len() - 2);
+        let week_day = WEEK_DAYS
+            .iter()
+            .position(|v| *v == day)
+            .ok_or_else(|| Error::from(format!("InvalidByDay '{}'", s)))?
+            as u8
+            + 1;
+        let n = match n {
+            "" => 0,
+            _ => match n.parse::<i8>() {
+                Ok(v) if v != 0 && (-53..=53).contains(&v) => v,
+                _ => return Err(Error::from(format!("InvalidByDay '{}'", s))),
+            },
+        };
+        Ok(Self { n, week_day })
+    }
+}
+
+impl Display for WeekdayNum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.n != 0 {
+            write!(f, "{}", self.n)?;
+        }
+        f.write_str(WEEK_DAYS[(self.week_day - 1) as usize % 7])
+    }
+}
+
+/// An RFC 5545 recurrence rule, "FREQ=MONTHLY;BYDAY=-1FR;COUNT=10".
+///
+/// Parse using `FromStr` impl, an "RRULE:" prefix is allowed.
+/// Format using the `Display` trait, serde uses the same string.
+/// Expand with `RRule::iter` from a start, occurrences keep the offset of the start,
+/// named time zones are not supported.
+/// ```rust
+/// use std::str::FromStr;
+/// use fastdate::{DateTime, RRule};
+/// let rule = RRule::from_str("FREQ=MONTHLY;BYDAY=-1FR;COUNT=3").unwrap();
+/// let start = DateTime::from_str("2022-12-01T09:00:00+08:00").unwrap();
+/// let v: Vec<String> = rule.iter(&start).map(|v| v.to_string()).collect();
+/// assert_eq!(v, [
+///     "2022-12-30T09:00:00+08:00",
+///     "2023-01-27T09:00:00+08:00",
+///     "2023-02-24T09:00:00+08:00",
+/// ]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RRule {
+    pub freq: Frequency,
+    /// 1...
+    pub interval: u32,
+    pub count: Option<u32>,
+    /// the last possible occurrence, inclusive
+    pub until: Option<DateTime>,
+    /// 0...59 (60 is read as 59)
+    pub by_second: Vec<u8>,
+    /// 0...59
+    pub by_minute: Vec<u8>,
+    /// 0...23
+    pub by_hour: Vec<u8>,
+    pub by_day: Vec<WeekdayNum>,
+    /// 1...31, -31...-1
+    pub by_month_day: Vec<i8>,
+    /// 1...366, -366...-1
+    pub by_year_day: Vec<i16>,
+    /// 1...53, -53...-1
+    pub by_week_no: Vec<i8>,
+    /// 1...12
+    pub by_month: Vec<u8>,
+    /// 1...366, -366...-1
+    pub by_set_pos: Vec<i16>,
+    /// WKST, 1(Monday)...7(Sunday)
+    pub week_start: u8,
+}
+
+impl RRule {
+    /// a rule of freq, every period, forever
+    pub fn new(freq: Frequency) -> Self {
+        Self {
+            freq,
+            interval: 1,
+            count: None,
+            until: None,
+            by_second: vec![],
+            by_minute: vec![],
+            by_hour: vec![],
+            by_day: vec![],
+            by_month_day: vec![],
+            by_year_day: vec![],
+            by_week_no: vec![],
+            by_month: vec![],
+            by_set_pos: vec![],
+            week_start: 1,
+        }
+    }
+
+    /// the occurrences from dtstart on, in order, at the offset of dtstart
+    pub fn iter(&self, dtstart: &DateTime) -> RRuleIter {
+        RRuleIter::new(self, dtstart)
+    }
+
+    /// check the values against RFC 5545
+    fn check(&self) -> Result<(), Error> {
+        fn range<T: PartialOrd>(v: &[T], min: T, max: T, name: &str) -> Result<(), Error> {
+            if v.iter().any(|x| *x < min || *x > max) {
+                return Err(Error::from(format!("OutOfRange{}", name)));
+            }
+            Ok(())
+        }
+        fn nonzero<T: Copy + Into<i64>>(v: &[T], max: i64, name: &str) -> Result<(), Error> {
+            if v.iter()
+                .any(|x| (*x).into() == 0 || (*x).into().abs() > max)
+            {
+                return Err(Error::from(format!("OutOfRange{}", name)));
+            }
+            Ok(())
+        }
+        if self.interval == 0 {
+            return Err(Error::from("OutOfRangeInterval"));
+        }
+        if self.count.is_some() && self.until.is_some() {
+            return Err(Error::from("CountWithUntil"));
+        }
+        range(&self.by_second, 0, 60, "BySecond")?;
+        range(&self.by_minute, 0, 59, "ByMinute")?;
+        range(&self.by_hour, 0, 23, "ByHour")?;
+        range(&self.by_month, 1, 12, "ByMonth")?;
+        nonzero(&self.by_month_day, 31, "ByMonthDay")?;
+        nonzero(&self.by_week_no, 53, "ByWeekNo")?;
+        nonzero(&self.by_year_day, 366, "ByYearDay")?;
+        nonzero(&self.by_set_pos, 366, "BySetPos")?;
+        if !(1..=7).contains(&self.week_start)
+            || self.by_day.iter().any(|v| !(1..=7).contains(&v.week_day))
+        {
+            return Err(Error::from("OutOfRangeWeekDay"));
+        }
+        let nth = self.by_day.iter().any(|v| v.n != 0);
+        if nth
+            && (!matches!(self.freq, Frequency::Monthly | Frequency::Yearly)
+                || (self.freq == Frequency::Yearly && !self.by_week_no.is_empty()))
+        {
+            return Err(Error::from("InvalidByDayNumber"));
+        }
+        if !self.by_week_no.is_empty() && self.freq != Frequency::Yearly {
+            return Err(Error::from("InvalidByWeekNo"));
+        }
+        if !self.by_month_day.is_empty() && self.freq == Frequency::Weekly {
+            return Err(Error::from("InvalidByMonthDay"));
+        }
+        if !self.by_year_day.is_empty()
+            && matches!(
+                self.freq,
+                Frequency::Daily | Frequency::Weekly | Frequency::Monthly
+            )
+        {
+            return Err(Error::from("InvalidByYearDay"));
+        }
+        Ok(())
+    }
+
+    /// does date pass the BYxxx rules of the date, within a period
+    fn matches_date(&self, date: &Date) -> bool {
+        if !self.by_month.is_empty() && !self.by_month.contains(&date.mon) {
+            return false;
+        }
+        let dim = days_in_month(date.year, date.mon) as i16;
+        if !self.by_month_day.is_empty()
+            && !self.by_month_day.iter().any(|v| {
+                let v = *v as i16;
+                v == date.day as i16 || v == date.day as i16 - dim - 1
+            })
+        {
+            return false;
+        }
+        let days = date.days_since_epoch();
+        let year_start = Date {
+            day: 1,
+            mon: 1,
+            year: date.year,
+        }
+        .days_since_epoch();
+        let diy = if is_leap_year(date.year) { 366 } else { 365 };
+        let doy = days - year_start + 1;
+        if !self.by_year_day.is_empty()
+            && !self
+                .by_year_day
+                .iter()
+                .any(|v| *v as i64 == doy || *v as i64 == doy - diy - 1)
+        {
+            return false;
+        }
+        if !self.by_week_no.is_empty() {
+            let (no, weeks) = week_no(date, self.week_start);
+            if !self
+                .by_week_no
+                .iter()
+                .any(|v| *v as i64 == no || *v as i64 == no - weeks - 1)
+            {
+                return false;
+            }
+        }
+        if !self.by_day.is_empty() {
+            let week_day = date.week_day();
+            // "-1FR" counts within the month, or within the year of a yearly rule without BYMONTH
+            let (index, len) = if self.freq == Frequency::Yearly && self.by_month.is_empty() {
+                (doy, diy)
+            } else {
+                (date.day as i64, dim as i64)
+            };
+            let nth = (index - 1) / 7 + 1;
+            let nth_back = -((len - index) / 7 + 1);
+            if !self.by_day.iter().any(|v| {
+                v.week_day == week_day && (v.n == 0 || v.n as i64 == nth || v.n as i64 == nth_back)
+            }) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// (week number, number of weeks) of the week-year of date, week 1 has at least 4 days in it
+fn week_no(date: &Date, week_start: u8) -> (i64, i64) {
+    let week1 = |year: i32| {
+        let jan4 = Date {
+            day: 4,
+            mon: 1,
+            year,
+        };
+        jan4.days_since_epoch() - (jan4.week_day() as i64 - week_start as i64).rem_euclid(7)
+    };
+    let days = date.days_since_epoch();
+    let mut year = date.year;
+    if days < week1(year) {
+        year -= 1;
+    } else if days >= week1(year + 1) {
+        year += 1;
+    }
+    let start = week1(year);
+    ((days - start) / 7 + 1, (week1(year + 1) - start) / 7)
+}
+
+/// read "19970902T090000Z", "19970902T090000" (at `offset_sec()`) or "19970902" (00:00 at `offset_sec()`)
+fn parse_ical_datetime(s: &str) -> Result<DateTime, Error> {
+    let b = s.as_bytes();
+    let ok = match b.len() {
+        8 => b.iter().all(u8::is_ascii_digit),
+        15 | 16 => {
+            b[..8].iter().all(u8::is_ascii_digit)
+                && b[8] == b'T'
+                && b[9..15].iter().all(u8::is_ascii_digit)
+                && (b.len() == 15 || b[15] == b'Z')
+        }
+        _ => false,
+    };
+    if !ok {
+        return Err(Error::from(format!("InvalidDateTime '{}'", s)));
+    }
+    let mut v = format!("{}-{}-{}", &s[0..4], &s[4..6], &s[6..8]);
+    if b.len() == 8 {
+        v.push_str("T00:00:00");
+    } else {
+        v.push_str(&format!(
+            "T{}:{}:{}{}",
+            &s[9..11],
+            &s[11..13],
+            &s[13..15],
+            &s[15..]
+        ));
+    }
+    DateTime::from_str(&v)
+}
+
+/// write "19970902T090000Z"
+fn format_ical_datetime(dt: &DateTime) -> String {
+    dt.clone().set_offset(0).format("YYYYMMDDThhmmssZ")
+}
+
+fn parse_list<T: FromStr>(v: &str, name: &str) -> Result<Vec<T>, Error> {
+    v.split(',')
+        .map(|x| {
+            x.parse::<T>()
+                .map_err(|_| Error::from(format!("Invalid{} '{}'", name, x)))
+        })
+        .collect()
+}
+
+fn write_list<T: Display>(f: &mut Formatter<'_>, name: &str, v: &[T]) -> fmt::Result {
+    if v.is_empty() {
+        return Ok(());
+    }
+    write!(f, ";{}=", name)?;
+    for (i, x) in v.iter().enumerate() {
+        if i != 0 {
+            f.write_str(",")?;
+        }
+        write!(f, "{}", x)?;
+    }
+    Ok(())
+}
+
+impl FromStr for RRule {
+    type Err = Error;
+
+    /// "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH;UNTIL=19971224T000000Z",
+    /// an UNTIL date "19971224" includes that whole day
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix("RRULE:").unwrap_or(s);
+        let mut freq = None;
+        let mut rule = RRule::new(Frequency::Yearly);
+        for part in s.split(';').filter(|v| !v.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| Error::from(format!("InvalidRulePart '{}'", part)))?;
+            match key {
+                "FREQ" => freq = Some(Frequency::from_str(value)?),
+                "INTERVAL" => {
+                    rule.interval = value
+                        .parse()
+                        .map_err(|_| Error::from(format!("InvalidInterval '{}'", value)))?
+                }
+                "COUNT" => {
+                    rule.count = Some(
+                        value
+                            .parse()
+                            .map_err(|_| Error::from(format!("InvalidCount '{}'", value)))?,
+                    )
+                }
+                "UNTIL" => {
+                    let mut until = parse_ical_datetime(value)?;
+                    if value.len() == 8 {
+                        until = until.end_of(crate::CalendarUnit::Day);
+                    }
+                    rule.until = Some(until)
+                }
+                "BYSECOND" => rule.by_second = parse_list(value, "BySecond")?,
+                "BYMINUTE" => rule.by_minute = parse_list(value, "ByMinute")?,
+                "BYHOUR" => rule.by_hour = parse_list(value, "ByHour")?,
+                "BYDAY" => rule.by_day = parse_list(value, "ByDay")?,
+                "BYMONTHDAY" => rule.by_month_day = parse_list(value, "ByMonthDay")?,
+                "BYYEARDAY" => rule.by_year_day = parse_list(value, "ByYearDay")?,
+                "BYWEEKNO" => rule.by_week_no = parse_list(value, "ByWeekNo")?,
+                "BYMONTH" => rule.by_month = parse_list(value, "ByMonth")?,
+                "BYSETPOS" => rule.by_set_pos = parse_list(value, "BySetPos")?,
+                "WKST" => {
+                    rule.week_start = WeekdayNum::from_str(value)
+                        .ok()
+                        .filter(|v| v.n == 0)
+                        .ok_or_else(|| Error::from(format!("InvalidWkst '{}'", value)))?
+                        .week_day
+                }
+                _ => return Err(Error::from(format!("InvalidRulePart '{}'", part))),
+            }
+        }
+        rule.freq = freq.ok_or_else(|| Error::from("MissingFreq"))?;
+        rule.check()?;
+        Ok(rule)
+    }
+}
+
+impl Display for RRule {
+    /// "FREQ=MONTHLY;COUNT=10;BYDAY=-1FR", UNTIL is written in UTC
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "FREQ={}", self.freq)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = &self.until {
+            write!(f, ";UNTIL={}", format_ical_datetime(until))?;
+        }
+        write_list(f, "BYSECOND", &self.by_second)?;
+        write_list(f, "BYMINUTE", &self.by_minute)?;
+        write_list(f, "BYHOUR", &self.by_hour)?;
+        write_list(f, "BYDAY", &self.by_day)?;
+        write_list(f, "BYMONTHDAY", &self.by_month_day)?;
+        write_list(f, "BYYEARDAY", &self.by_year_day)?;
+        write_list(f, "BYWEEKNO", &self.by_week_no)?;
+        write_list(f, "BYMONTH", &self.by_month)?;
+        write_list(f, "BYSETPOS", &self.by_set_pos)?;
+        if self.week_start != 1 {
+            write!(f, ";WKST={}", WEEK_DAYS[(self.week_start - 1) as usize % 7])?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for RRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RRule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        RRule::from_str(&String::deserialize(deserializer)?)
+            .map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
+
+/// The occurrences of an `RRule`, see `RRule::iter`
+#[derive(Clone, Debug)]
+pub struct RRuleIter {
+    /// the rule with the BYxxx defaults of dtstart filled in
+    rule: RRule,
+    dtstart: DateTime,
+    /// local seconds since 1970-01-01 of dtstart
+    start_secs: i64,
+    /// the next period, or the next day of a rule more frequent than daily
+    period: i64,
+    /// periods in a row without occurrences, to stop rules that never occur
+    empty_periods: u32,
+    buffer: VecDeque<DateTime>,
+    emitted: u32,
+    /// count dtstart toward COUNT when it is not the first occurrence, see `RRuleSet`
+    count_dtstart: bool,
+    done: bool,
+}
+
+impl RRuleIter {
+    fn new(rule: &RRule, dtstart: &DateTime) -> Self {
+        let mut rule = rule.clone();
+        let date = Date::from(dtstart.clone());
+        let freq = rule.freq;
+        let no_day_rule = rule.by_week_no.is_empty()
+            && rule.by_year_day.is_empty()
+            && rule.by_month_day.is_empty()
+            && rule.by_day.is_empty();
+        match freq {
+            Frequency::Yearly if no_day_rule => {
+                if rule.by_month.is_empty() {
+                    rule.by_month = vec![date.mon];
+                }
+                rule.by_month_day = vec![date.day as i8];
+            }
+            Frequency::Monthly if no_day_rule => rule.by_month_day = vec![date.day as i8],
+            Frequency::Weekly if rule.by_day.is_empty() => {
+                rule.by_day = vec![WeekdayNum {
+                    n: 0,
+                    week_day: date.week_day(),
+                }]
+            }
+            _ => {}
+        }
+        if freq >= Frequency::Daily && rule.by_hour.is_empty() {
+            rule.by_hour = vec![dtstart.hour()];
+        }
+        if freq >= Frequency::Hourly && rule.by_minute.is_empty() {
+            rule.by_minute = vec![dtstart.minute()];
+        }
+        if freq >= Frequency::Minutely && rule.by_second.is_empty() {
+            rule.by_second = vec![dtstart.sec()];
+        }
+        for v in &mut rule.by_second {
+            *v = (*v).min(59);
+        }
+        let days = date.days_since_epoch();
+        let secs = Time::from(dtstart.clone()).nanos_of_day() as i64 / 1_000_000_000;
+        let period = match freq {
+            Frequency::Yearly => date.year as i64,
+            Frequency::Monthly => date.year as i64 * 12 + date.mon as i64 - 1,
+            Frequency::Weekly => {
+                days - (date.week_day() as i64 - rule.week_start as i64).rem_euclid(7)
+            }
+            _ => days,
+        };
+        Self {
+            rule,
+            dtstart: dtstart.clone(),
+            start_secs: days * 86400 + secs.min(86399),
+            period,
+            empty_periods: 0,
+            buffer: VecDeque::new(),
+            emitted: 0,
+            count_dtstart: false,
+            done: false,
+        }
+    }
+
+    /// seconds of a period of a rule more frequent than daily
+    fn unit_secs(&self) -> i64 {
+        match self.rule.freq {
+            Frequency::Hourly => 3600,
+            Frequency::Minutely => 60,
+            _ => 1,
+        }
+    }
+
+    /// (first day, days) of the current period of a daily or less frequent rule
+    fn period_days(&self) -> Option<(i64, i64)> {
+        let p = self.period;
+        match self.rule.freq {
+            Frequency::Yearly => {
+                let year = i32::try_from(p).ok()?;
+                let first = Date::new(year, 1, 1).ok()?.days_since_epoch();
+                Some((first, if is_leap_year(year) { 366 } else { 365 }))
+            }
+            Frequency::Monthly => {
+                let year = i32::try_from(p.div_euclid(12)).ok()?;
+                let mon = (p.rem_euclid(12) + 1) as u8;
+                let first = Date::new(year, mon, 1).ok()?.days_since_epoch();
+                Some((first, days_in_month(year, mon) as i64))
+            }
+            Frequency::Weekly => Some((p, 7)),
+            _ => Some((p, 1)),
+        }
+    }
+
+    /// the times of day (seconds) of a daily or less frequent rule
+    fn times_of_day(&self) -> Vec<i64> {
+        let mut v = Vec::new();
+        for h in &self.rule.by_hour {
+            for m in &self.rule.by_minute {
+                for s in &self.rule.by_second {
+                    v.push(*h as i64 * 3600 + *m as i64 * 60 + *s as i64);
+                }
+            }
+        }
+        v.sort();
+        v.dedup();
+        v
+    }
+
+    /// select BYSETPOS from the sorted occurrences of one period
+    fn set_pos(&self, v: Vec<i64>) -> Vec<i64> {
+        if self.rule.by_set_pos.is_empty() {
+            return v;
+        }
+        let len = v.len() as i64;
+        let mut selected: Vec<i64> = self
+            .rule
+            .by_set_pos
+            .iter()
+            .filter_map(|p| {
+                let index = if *p > 0 {
+                    *p as i64 - 1
+                } else {
+                    len + *p as i64
+                };
+                (0..len).contains(&index).then(|| v[index as usize])
+            })
+            .collect();
+        selected.sort();
+        selected.dedup();
+        selected
+    }
+
+    /// local seconds since 1970-01-01 of the occurrences of the current period, then move to the next
+    fn next_period(&mut self) -> Option<Vec<i64>> {
+        let interval = self.rule.interval as i64;
+        if self.rule.freq >= Frequency::Daily {
+            let (first, len) = self.period_days()?;
+            if first + len - 1 > Date::MAX.days_since_epoch() {
+                return None;
+            }
+            let times = self.times_of_day();
+            let mut v = Vec::new();
+            for day in first..first + len {
+                if self.rule.matches_date(&Date::from_days_since_epoch(day)) {
+                    v.extend(times.iter().map(|t| day * 86400 + t));
+                }
+            }
+            self.period += match self.rule.freq {
+                Frequency::Weekly => 7 * interval,
+                _ => interval,
+            };
+            if !self.hit(!v.is_empty()) {
+                return None;
+            }
+            return Some(self.set_pos(v));
+        }
+        // rules more frequent than daily work day by day
+        let unit = self.unit_secs();
+        let step = unit * interval;
+        let day = self.period;
+        if day > Date::MAX.days_since_epoch() {
+            return None;
+        }
+        let date = Date::from_days_since_epoch(day);
+        let mut v = Vec::new();
+        let first = (day * 86400).max(self.start_secs);
+        let mut secs =
+            self.start_secs + (first - self.start_secs + step - 1).div_euclid(step) * step;
+        if self.rule.matches_date(&date) {
+            while secs < (day + 1) * 86400 {
+                let s = secs - day * 86400;
+                let (h, m, sec) = ((s / 3600) as u8, (s / 60 % 60) as u8, (s % 60) as u8);
+                let r = &self.rule;
+                let ok = (r.by_hour.is_empty() || r.by_hour.contains(&h))
+                    && (r.freq > Frequency::Minutely
+                        || r.by_minute.is_empty()
+                        || r.by_minute.contains(&m))
+                    && (r.freq > Frequency::Secondly
+                        || r.by_second.is_empty()
+                        || r.by_second.contains(&sec));
+                if ok {
+                    let mut period = Vec::new();
+                    let base = secs - s + h as i64 * 3600;
+                    match r.freq {
+                        Frequency::Hourly => {
+                            for m in &r.by_minute {
+                                for s in &r.by_second {
+                                    period.push(base + *m as i64 * 60 + *s as i64);
+                                }
+                            }
+                        }
+                        Frequency::Minutely => {
+                            for s in &r.by_second {
+                                period.push(base + m as i64 * 60 + *s as i64);
+                            }
+                        }
+                        _ => period.push(secs),
+                    }
+                    period.sort();
+                    period.dedup();
+                    v.extend(self.set_pos(period));
+                }
+                secs += step;
+            }
+        }
+        // jump to the day of the next period
+        let next = secs.max((day + 1) * 86400);
+        let next = self.start_secs + (next - self.start_secs + step - 1).div_euclid(step) * step;
+        self.period = next.div_euclid(86400).max(day + 1);
+        if !self.hit(!v.is_empty()) {
+            return None;
+        }
+        Some(v)
+    }
+
+    /// count the periods in a row without occurrences, false once there are too many
+    fn hit(&mut self, hit: bool) -> bool {
+        if hit {
+            self.empty_periods = 0;
+        } else {
+            self.empty_periods += 1;
+        }
+        self.empty_periods <= MAX_EMPTY_PERIODS
+    }
+}
+
+impl Iterator for RRuleIter {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.is_empty() && !self.done {
+            let Some(secs) = self.next_period() else {
+                self.done = true;
+                break;
+            };
+            let offset = self.dtstart.offset();
+            let nano = self.dtstart.nano() % 1_000_000_000;
+            for s in secs {
+                let date = Date::from_days_since_epoch(s.div_euclid(86400));
+                let time = Time::from_nanos_of_day(
+                    s.rem_euclid(86400) as u64 * 1_000_000_000 + nano as u64,
+                );
+                let Ok(dt) = DateTime::new(date, time, offset) else {
+                    self.done = true;
+                    break;
+                };
+                if dt < self.dtstart {
+                    continue;
+                }
+                if let Some(until) = &self.rule.until
+                    && &dt > until
+                {
+                    self.done = true;
+                    break;
+                }
+                self.buffer.push_back(dt);
+            }
+        }
+        if self.count_dtstart {
+            self.count_dtstart = false;
+            let first = self.buffer.front().map(DateTime::unix_timestamp_nano);
+            if first != Some(self.dtstart.unix_timestamp_nano()) {
+                self.emitted += 1;
+            }
+        }
+        if let Some(count) = self.rule.count
+            && self.emitted >= count
+        {
+            return None;
+        }
+        let v = self.buffer.pop_front()?;
+        self.emitted += 1;
+        Some(v)
+    }
+}
+
+/// A recurrence set, DTSTART, RRULE, RDATE and EXDATE lines of RFC 5545.
+///
+/// The occurrences are dtstart, the occurrences of the rules and the rdates,
+/// minus the exdates, in order and without duplicates.
+/// As in RFC 5545 dtstart is the first occurrence of every rule and counts toward its COUNT,
+/// also when the rule itself would not produce it.
+/// ```rust
+/// use std::str::FromStr;
+/// let set = fastdate::RRuleSet::from_str("DTSTART:20221212T090000Z
+/// RRULE:FREQ=DAILY;COUNT=3
+/// EXDATE:20221213T090000Z
+/// RDATE:20221220T090000Z").unwrap();
+/// let v: Vec<String> = set.iter().map(|v| v.to_string()).collect();
+/// assert_eq!(v, ["2022-12-12T09:00:00Z", "2022-12-14T09:00:00Z", "2022-12-20T09:00:00Z"]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RRuleSet {
+    pub dtstart: DateTime,
+    pub rrules: Vec<RRule>,
+    pub rdates: Vec<DateTime>,
+    pub exdates: Vec<DateTime>,
+}
+
+impl RRuleSet {
+    /// a set of only dtstart
+    pub fn new(dtstart: DateTime) -> Self {
+        Self {
+            dtstart,
+            rrules: vec![],
+            rdates: vec![],
+            exdates: vec![],
+        }
+    }
+
+    /// add a rule
+    pub fn add_rrule(mut self, rrule: RRule) -> Self {
+        self.rrules.push(rrule);
+        self
+    }
+
+    /// add an extra occurrence
+    pub fn add_rdate(mut self, rdate: DateTime) -> Self {
+        self.rdates.push(rdate);
+        self
+    }
+
+    /// add an excluded occurrence
+    pub fn add_exdate(mut self, exdate: DateTime) -> Self {
+        self.exdates.push(exdate);
+        self
+    }
+
+    /// the occurrences, in order
+    pub fn iter(&self) -> RRuleSetIter {
+        let mut rdates = self.rdates.clone();
+        rdates.push(self.dtstart.clone());
+        rdates.sort();
+        RRuleSetIter {
+            rules: self
+                .rrules
+                .iter()
+                .map(|v| {
+                    let mut iter = v.iter(&self.dtstart);
+                    // dtstart is added below, it takes the place of the first occurrence
+                    iter.count_dtstart = true;
+                    (iter, None)
+                })
+                .collect(),
+            rdates: rdates.into(),
+            exdates: self
+                .exdates
+                .iter()
+                .map(DateTime::unix_timestamp_nano)
+                .collect(),
+            last: None,
+        }
+    }
+}
+
+/// The occurrences of an `RRuleSet`, see `RRuleSet::iter`
+#[derive(Clone, Debug)]
+pub struct RRuleSetIter {
+    /// each rule with its next occurrence
+    rules: Vec<(RRuleIter, Option<DateTime>)>,
+    rdates: VecDeque<DateTime>,
+    exdates: HashSet<i128>,
+    last: Option<i128>,
+}
+
+impl Iterator for RRuleSetIter {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            for (iter, next) in &mut self.rules {
+                if next.is_none() {
+                    *next = iter.next();
+                }
+            }
+            let rule = (0..self.rules.len())
+                .filter(|i| self.rules[*i].1.is_some())
+                .min_by(|a, b| self.rules[*a].1.cmp(&self.rules[*b].1));
+            let v = match (rule, self.rdates.front()) {
+                (Some(i), Some(rdate)) if Some(rdate) < self.rules[i].1.as_ref() => {
+                    self.rdates.pop_front()
+                }
+                (Some(i), _) => self.rules[i].1.take(),
+                (None, _) => self.rdates.pop_front(),
+            }?;
+            let key = v.unix_timestamp_nano();
+            if self.last == Some(key) || self.exdates.contains(&key) {
+                continue;
+            }
+            self.last = Some(key);
+            return Some(v);
+        }
+    }
+}
+
+/// read the value of a "NAME;VALUE=DATE:value" line, a TZID is not supported
+fn property_value<'a>(line: &'a str, name: &str) -> Result<Option<&'a str>, Error> {
+    let Some((key, value)) = line.split_once(':') else {
+        return Ok(None);
+    };
+    let mut params = key.split(';');
+    if params.next() != Some(name) {
+        return Ok(None);
+    }
+    for param in params {
+        if param.starts_with("TZID=") {
+            return Err(Error::from(format!("UnsupportedTimeZone '{}'", param)));
+        }
+    }
+    Ok(Some(value))
+}
+
+impl FromStr for RRuleSet {
+    type Err = Error;
+
+    /// "DTSTART:", "RRULE:", "RDATE:" and "EXDATE:" lines, RDATE and EXDATE may be comma separated lists
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut dtstart = None;
+        let (mut rrules, mut rdates, mut exdates) = (vec![], vec![], vec![]);
+        for line in s.lines().map(str::trim).filter(|v| !v.is_empty()) {
+            if let Some(v) = property_value(line, "DTSTART")? {
+                dtstart = Some(parse_ical_datetime(v)?);
+            } else if let Some(v) = property_value(line, "RRULE")? {
+                rrules.push(RRule::from_str(v)?);
+            } else if let Some(v) = property_value(line, "RDATE")? {
+                for x in v.split(',') {
+                    rdates.push(parse_ical_datetime(x)?);
+                }
+            } else if let Some(v) = property_value(line, "EXDATE")? {
+                for x in v.split(',') {
+                    exdates.push(parse_ical_datetime(x)?);
+                }
+            } else {
+                return Err(Error::from(format!("InvalidLine '{}'", line)));
+            }
+        }
+        Ok(Self {
+            dtstart: dtstart.ok_or_else(|| Error::from("MissingDtStart"))?,
+            rrules,
+            rdates,
+            exdates,
+        })
+    }
+}
+
+impl Display for RRuleSet {
+    /// one line per property, date times are written in UTC
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "DTSTART:{}", format_ical_datetime(&self.dtstart))?;
+        for rule in &self.rrules {
+            write!(f, "\nRRULE:{}", rule)?;
+        }
+        for (name, v) in [("RDATE", &self.rdates), ("EXDATE", &self.exdates)] {
+            if !v.is_empty() {
+                write!(f, "\n{}:", name)?;
+                for (i, x) in v.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(",")?;
+                    }
+                    f.write_str(&format_ical_datetime(x))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,516 @@
+//! Modules for `#[serde(with = "...")]`, writing dates and times as numbers.
+//!
+//! * `ts_seconds`, `ts_milliseconds`, `ts_microseconds` and `ts_nanoseconds`, a `DateTime` as an
+//!   integer since 1970-01-01T00:00:00Z, truncated to the unit, read back in UTC
+//! * `flexible`, a `DateTime` written as RFC 3339, read from RFC 3339 or from seconds,
+//!   which may have a fraction, rounded to microseconds
+//! * `date_days`, a `Date` as the days since 1970-01-01
+//! * `time_seconds`, a `Time` as the seconds since midnight, the fraction is dropped
+//!
+//! Each has an `_option` module for an `Option`, `None` is written as null.
+//!
+//! For text in other formats see `Format` and `serde_format!`,
+//! for values read from numbers or maps as well as strings see `Lenient`.
+//! ```rust
+//! use fastdate::DateTime;
+//! use serde::{Serialize, Serializer};
+//! struct Event {
+//!     at: DateTime,
+//! }
+//! impl Serialize for Event {
+//!     fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+//!         fastdate::serde::ts_milliseconds::serialize(&self.at, s)
+//!     }
+//! }
+//! let v = Event { at: DateTime::from_timestamp_millis(1670929934123) };
+//! assert_eq!(serde_json::to_string(&v).unwrap(), "1670929934123");
+//! let mut de = serde_json::Deserializer::from_str("1670929934123");
+//! let at = fastdate::serde::ts_milliseconds::deserialize(&mut de).unwrap();
+//! assert_eq!(at, v.at);
+//! ```
+
+use crate::date::DateVisitor;
+use crate::datetime::DateTimeVisitor;
+use crate::error::Error;
+use crate::time::TimeVisitor;
+use crate::{Date, DateTime, Time};
+use ::serde::de;
+use ::serde::{Deserialize, Deserializer, Serializer};
+
+/// an `_option` module from the module of the value
+macro_rules! option_module {
+    ($name:ident, $base:ident, $ty:ty) => {
+        #[doc = concat!("`Option` of `", stringify!($base), "`, `None` is null")]
+        pub mod $name {
+            use super::$base;
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            struct Ref<'a>(&'a $ty);
+
+            impl Serialize for Ref<'_> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    $base::serialize(self.0, serializer)
+                }
+            }
+
+            struct Value($ty);
+
+            impl<'de> Deserialize<'de> for Value {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    $base::deserialize(deserializer).map(Value)
+                }
+            }
+
+            pub fn serialize<S>(v: &Option<$ty>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match v {
+                    Some(v) => serializer.serialize_some(&Ref(v)),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<$ty>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Ok(Option::<Value>::deserialize(deserializer)?.map(|v| v.0))
+            }
+        }
+    };
+}
+
+/// a module writing a DateTime as an integer timestamp
+macro_rules! timestamp_module {
+    ($name:ident, $unit:literal, $to:expr, $from:expr) => {
+        #[doc = concat!("A `DateTime` as ", $unit, " since 1970-01-01T00:00:00Z, read back in UTC")]
+        pub mod $name {
+            use crate::DateTime;
+            use serde::{Deserialize, Deserializer, Serializer};
+
+            pub fn serialize<S>(v: &DateTime, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                use serde::ser::Error;
+                let to: fn(&DateTime) -> Option<i64> = $to;
+                serializer
+                    .serialize_i64(to(v).ok_or_else(|| S::Error::custom("OutOfRangeTimestamp"))?)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                use serde::de::Error;
+                let from: fn(i64) -> Result<DateTime, crate::error::Error> = $from;
+                from(i64::deserialize(deserializer)?).map_err(|e| D::Error::custom(e.to_string()))
+            }
+        }
+    };
+}
+
+timestamp_module!(
+    ts_seconds,
+    "seconds",
+    |v| Some(v.unix_timestamp()),
+    DateTime::try_from_timestamp
+);
+timestamp_module!(
+    ts_milliseconds,
+    "milliseconds",
+    |v| i64::try_from(v.unix_timestamp_nano().div_euclid(1_000_000)).ok(),
+    DateTime::try_from_timestamp_millis
+);
+timestamp_module!(
+    ts_microseconds,
+    "microseconds",
+    |v| i64::try_from(v.unix_timestamp_nano().div_euclid(1_000)).ok(),
+    DateTime::try_from_timestamp_micros
+);
+timestamp_module!(
+    ts_nanoseconds,
+    "nanoseconds, within 1677-09-21...2262-04-11",
+    |v| i64::try_from(v.unix_timestamp_nano()).ok(),
+    |v| DateTime::try_from_timestamp_nano(v as i128)
+);
+option_module!(ts_seconds_option, ts_seconds, crate::DateTime);
+option_module!(ts_milliseconds_option, ts_milliseconds, crate::DateTime);
+option_module!(ts_microseconds_option, ts_microseconds, crate::DateTime);
+option_module!(ts_nanoseconds_option, ts_nanoseconds, crate::DateTime);
+option_module!(flexible_option, flexible, crate::DateTime);
+option_module!(date_days_option, date_days, crate::Date);
+option_module!(time_seconds_option, time_seconds, crate::Time);
+
+/// A `DateTime` written as RFC 3339, read from an RFC 3339 string,
+/// or from seconds since 1970-01-01T00:00:00Z, an integer string, or a number
+/// which may have a fraction, rounded to microseconds, as `Lenient` does
+pub mod flexible {
+    use crate::DateTime;
+    use crate::datetime::DateTimeVisitor;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S>(v: &DateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(v)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_str(DateTimeVisitor { lenient: true });
+        }
+        deserializer.deserialize_any(DateTimeVisitor { lenient: true })
+    }
+}
+
+/// A `Date` as the days since 1970-01-01
+pub mod date_days {
+    use crate::Date;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(v: &Date, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(v.days_since_epoch())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Date, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Date::try_from_days_since_epoch(i64::deserialize(deserializer)?)
+            .map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
+
+/// A `Time` as the seconds since midnight 0...86399, the fraction of a second is dropped
+pub mod time_seconds {
+    use crate::Time;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(v: &Time, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u32(v.hour as u32 * 3600 + v.minute as u32 * 60 + v.sec as u32)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        let v = u32::deserialize(deserializer)?;
+        if v >= 86400 {
+            return Err(D::Error::custom("OutOfRangeTime"));
+        }
+        Ok(Time::from_nanos_of_day(v as u64 * 1_000_000_000))
+    }
+}
+
+/// A `DateTime`, `Date` or `Time` that deserializes from more shapes than the strict string,
+/// while it serializes as the value does.
+///
+/// * `DateTime` from seconds since 1970-01-01T00:00:00Z, an integer, an integer string or a float
+///   rounded to microseconds, or a map `{"secs": i64, "nanos": u32}`
+/// * `Date` from days since 1970-01-01
+/// * `Time` from seconds since midnight, an integer or a float, or a map `{"secs": u64, "nanos": u32}`
+///
+/// Formats that are not human readable read the value's own compact form.
+/// ```rust
+/// use fastdate::serde::Lenient;
+/// use fastdate::DateTime;
+/// let v: Lenient<DateTime> = serde_json::from_str(r#"{"secs": 1670901134, "nanos": 5}"#).unwrap();
+/// assert_eq!(v.0.to_string(), "2022-12-13T03:12:14.000000005Z");
+/// let v: Lenient<DateTime> = serde_json::from_str("1670901134.5").unwrap();
+/// assert_eq!(v.0.to_string(), "2022-12-13T03:12:14.5Z");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Lenient<T>(pub T);
+
+impl<T> Lenient<T> {
+    /// the value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: ::serde::Serialize> ::serde::Serialize for Lenient<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Lenient<DateTime> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return DateTime::deserialize(deserializer).map(Lenient);
+        }
+        deserializer
+            .deserialize_any(DateTimeVisitor { lenient: true })
+            .map(Lenient)
+    }
+}
+
+impl<'de> Deserialize<'de> for Lenient<Date> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return Date::deserialize(deserializer).map(Lenient);
+        }
+        deserializer
+            .deserialize_any(DateVisitor { lenient: true })
+            .map(Lenient)
+    }
+}
+
+impl<'de> Deserialize<'de> for Lenient<Time> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return Time::deserialize(deserializer).map(Lenient);
+        }
+        deserializer
+            .deserialize_any(TimeVisitor { lenient: true })
+            .map(Lenient)
+    }
+}
+
+#[doc(hidden)]
+pub use ::serde as __serde;
+
+/// A per-field text format for serde, using the tokens of `DateTime::format` and `DateTime::parse`,
+/// "YYYY", "MM", "DD", "hh", "mm", "ss", ".000000", ".000000000", "+00:00" and "Z".
+///
+/// Written in `output`, read from the first of `inputs` the text fits (or from `output`).
+/// Without an offset in the text, it is read at `offset`, or at the local offset when `None`,
+/// and a `DateTime` is moved to `offset` before it is written.
+///
+/// Use `serde_format!` to make a module for `#[serde(with = "...")]`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Format {
+    pub output: &'static str,
+    pub inputs: &'static [&'static str],
+    pub offset: Option<i32>,
+}
+
+impl Format {
+    /// written and read in one format
+    pub const fn new(output: &'static str) -> Self {
+        Self {
+            output,
+            inputs: &[],
+            offset: None,
+        }
+    }
+
+    /// read from any of these formats, tried in order
+    pub const fn with_inputs(mut self, inputs: &'static [&'static str]) -> Self {
+        self.inputs = inputs;
+        self
+    }
+
+    /// the offset in seconds of a text without one
+    pub const fn with_offset(mut self, offset_sec: i32) -> Self {
+        self.offset = Some(offset_sec);
+        self
+    }
+
+    /// write in `output`, at `offset` when set
+    pub fn format(&self, v: &DateTime) -> String {
+        match self.offset {
+            Some(offset) => v.clone().set_offset(offset).format(self.output),
+            None => v.format(self.output),
+        }
+    }
+
+    /// read from the first of `inputs` (or `output`) the text fits
+    pub fn parse(&self, arg: &str) -> Result<DateTime, Error> {
+        let inputs: &[&str] = if self.inputs.is_empty() {
+            &[self.output]
+        } else {
+            self.inputs
+        };
+        for format in inputs {
+            if !fits(format, arg) {
+                continue;
+            }
+            let has_offset = format.contains('Z') || format.contains("+00:00");
+            return match self.offset {
+                Some(offset) if !has_offset => {
+                    let offset = DateTime::from_timestamp(0)
+                        .set_offset(offset)
+                        .format("+00:00");
+                    DateTime::parse(&format!("{}+00:00", format), &format!("{}{}", arg, offset))
+                }
+                _ => DateTime::parse(format, arg),
+            };
+        }
+        Err(Error::from(format!("NoFormatFits '{}'", arg)))
+    }
+
+    pub fn serialize<S>(&self, v: &DateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.format(v))
+    }
+
+    pub fn deserialize<'de, D>(&self, deserializer: D) -> Result<DateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.parse(&String::deserialize(deserializer)?)
+            .map_err(|e| de::Error::custom(e.to_string()))
+    }
+
+    pub fn serialize_option<S>(
+        &self,
+        v: &Option<DateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match v {
+            Some(v) => serializer.serialize_some(&self.format(v)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize_option<'de, D>(&self, deserializer: D) -> Result<Option<DateTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|v| self.parse(&v).map_err(|e| de::Error::custom(e.to_string())))
+            .transpose()
+    }
+}
+
+/// does arg have the shape of format? literal characters must match, tokens must be digits
+fn fits(format: &str, arg: &str) -> bool {
+    let (f, a) = (format.as_bytes(), arg.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    let digits =
+        |j: usize, n: usize| a.len() >= j + n && a[j..j + n].iter().all(u8::is_ascii_digit);
+    while i < f.len() {
+        let rest = &f[i..];
+        let (token, ok) = if rest.starts_with(b".000000000") {
+            (10, a.get(j) == Some(&b'.') && digits(j + 1, 9))
+        } else if rest.starts_with(b".000000") {
+            (7, a.get(j) == Some(&b'.') && digits(j + 1, 6))
+        } else if rest.starts_with(b"+00:00") {
+            (
+                6,
+                matches!(a.get(j), Some(b'+' | b'-'))
+                    && digits(j + 1, 2)
+                    && a.get(j + 3) == Some(&b':')
+                    && digits(j + 4, 2),
+            )
+        } else if rest.starts_with(b"YYYY") {
+            (4, digits(j, 4))
+        } else if [&b"MM"[..], b"DD", b"hh", b"mm", b"ss"]
+            .iter()
+            .any(|v| rest.starts_with(v))
+        {
+            (2, digits(j, 2))
+        } else {
+            (1, a.get(j) == Some(&f[i]))
+        };
+        if !ok {
+            return false;
+        }
+        i += token;
+        j += token;
+    }
+    j == a.len()
+}
+
+/// Make a module for `#[serde(with = "...")]` from a `fastdate::serde::Format`,
+/// with an `option` module inside it for `Option<DateTime>`.
+/// ```rust
+/// fastdate::serde_format!(pub mod legacy = fastdate::serde::Format::new("YYYY/MM/DD hh:mm:ss")
+///     .with_inputs(&["YYYY/MM/DD hh:mm:ss", "YYYY-MM-DD hh:mm:ss"])
+///     .with_offset(8 * 3600));
+///
+/// let v = legacy::deserialize(serde_json::json!("2022-12-13 11:12:14")).unwrap();
+/// assert_eq!(v.to_string(), "2022-12-13T11:12:14+08:00");
+/// let v = legacy::serialize(&v, serde_json::value::Serializer).unwrap();
+/// assert_eq!(v, "2022/12/13 11:12:14");
+/// ```
+#[macro_export]
+macro_rules! serde_format {
+    ($vis:vis mod $name:ident = $format:expr) => {
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::serde::__serde::{Deserializer, Serializer};
+
+            const FORMAT: $crate::serde::Format = $format;
+
+            pub fn serialize<S>(v: &$crate::DateTime, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                FORMAT.serialize(v, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<$crate::DateTime, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                FORMAT.deserialize(deserializer)
+            }
+
+            /// the same format for `Option<DateTime>`, `None` is null
+            pub mod option {
+                use super::FORMAT;
+                use $crate::serde::__serde::{Deserializer, Serializer};
+
+                pub fn serialize<S>(
+                    v: &Option<$crate::DateTime>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    FORMAT.serialize_option(v, serializer)
+                }
+
+                pub fn deserialize<'de, D>(
+                    deserializer: D,
+                ) -> Result<Option<$crate::DateTime>, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    FORMAT.deserialize_option(deserializer)
+                }
+            }
+        }
+    };
+}
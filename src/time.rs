@@ -1,20 +1,26 @@
+use crate::datetime::SecsNanos;
 use crate::error::Error;
-use crate::{DateTime, get_digit, get_digit_unchecked};
+use crate::{DateTime, SignedDuration, get_digit, get_digit_unchecked};
+use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp;
 use std::fmt::{Display, Formatter};
+use std::ops::Sub;
 use std::str::FromStr;
 use std::time::Duration;
 
+pub(crate) const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Time {
-    /// 0...999999999
-    pub nano: u32,
+    /// 0...999999999, or up to 1999999999 within a leap second, when sec is 59
+    pub(crate) nano: u32,
     /// 0...59
-    pub sec: u8,
+    pub(crate) sec: u8,
     /// 0...59
-    pub minute: u8,
+    pub(crate) minute: u8,
     /// 0...23
-    pub hour: u8,
+    pub(crate) hour: u8,
 }
 
 impl Time {
@@ -54,11 +60,21 @@ impl Time {
             return Err(Error::E("OutOfRangeMinute".to_string()));
         }
         let mut length: usize = 5;
+        // "hh:mm", the seconds are optional
+        if bytes.get(offset + 5) != Some(&b':') {
+            let t = Self {
+                nano: 0,
+                sec: 0,
+                minute,
+                hour,
+            };
+            return Ok((t, length));
+        }
         let (second, nano) = {
             let s1 = get_digit!(bytes, offset + 6, "InvalidCharSecond");
             let s2 = get_digit!(bytes, offset + 7, "InvalidCharSecond");
-            let second = s1 * 10 + s2;
-            if second > 59 {
+            let mut second = s1 * 10 + s2;
+            if second > 60 {
                 return Err(Error::E("OutOfRangeSecond".to_string()));
             }
             length = 8;
@@ -96,6 +112,11 @@ impl Time {
                     i += 1;
                 }
             }
+            if second == 60 {
+                // a leap second is held as nano >= 1_000_000_000 of second 59
+                second = 59;
+                nano += 1_000_000_000;
+            }
             (second, nano)
         };
         let t = Self {
@@ -107,32 +128,322 @@ impl Time {
         Ok((t, length))
     }
 
-    /// 0...999999999
-    pub fn set_nano(mut self, arg: u32) -> Self {
+    /// parse any of the forms of `FromStr`
+    fn parse_str(arg: &str) -> Result<Self, Error> {
+        if let Some((body, pm)) = split_meridiem(arg) {
+            return Self::parse_clock12(body, pm);
+        }
+        let bytes = arg.as_bytes();
+        let digits = bytes.iter().take_while(|c| c.is_ascii_digit()).count();
+        if (digits == 4 || digits == 6) && !matches!(bytes.get(digits), Some(b'-' | b':')) {
+            // basic format "hhmm" or "hhmmss.999999999"
+            let mut buf = Vec::with_capacity(bytes.len() + 2);
+            buf.extend_from_slice(&bytes[..2]);
+            buf.push(b':');
+            buf.extend_from_slice(&bytes[2..4]);
+            if digits == 6 {
+                buf.push(b':');
+            }
+            buf.extend_from_slice(&bytes[4..]);
+            return Self::parse_bytes_whole(&buf);
+        }
+        if digits > 2 {
+            return Err(Error::E("InvalidCharMinute".to_string()));
+        }
+        Self::parse_bytes_whole(bytes)
+    }
+
+    /// parse like `parse_bytes_partial`, and reject input left after the time
+    fn parse_bytes_whole(bytes: &[u8]) -> Result<Self, Error> {
+        let (t, len) = Self::parse_bytes_partial(bytes, 0)?;
+        if len != bytes.len() {
+            return Err(Error::E("InvalidChar".to_string()));
+        }
+        Ok(t)
+    }
+
+    /// parse the part before AM/PM of a 12-hour clock, "2", "2:30" or "02:30:15.5"
+    fn parse_clock12(body: &str, pm: bool) -> Result<Self, Error> {
+        let bytes = body.as_bytes();
+        let digits = bytes.iter().take_while(|c| c.is_ascii_digit()).count();
+        let mut buf = Vec::with_capacity(bytes.len() + 4);
+        match digits {
+            1 => buf.push(b'0'),
+            2 => {}
+            _ => return Err(Error::E("InvalidCharHour".to_string())),
+        }
+        buf.extend_from_slice(bytes);
+        if digits == bytes.len() {
+            buf.extend_from_slice(b":00");
+        }
+        let mut t = Self::parse_bytes_whole(&buf)?;
+        if t.hour < 1 || t.hour > 12 {
+            return Err(Error::E("OutOfRangeHour".to_string()));
+        }
+        t.hour %= 12;
+        if pm {
+            t.hour += 12;
+        }
+        Ok(t)
+    }
+
+    /// parse like `FromStr`, with a policy for a leap second "23:59:60"
+    /// ```rust
+    /// use fastdate::{LeapSecond, Time};
+    /// let t = Time::from_str_leap("23:59:60.5", LeapSecond::Clamp).unwrap();
+    /// assert_eq!(t.to_string(), "23:59:59.999999999");
+    /// assert!(Time::from_str_leap("23:59:60.5", LeapSecond::Reject).is_err());
+    /// ```
+    pub fn from_str_leap(arg: &str, policy: LeapSecond) -> Result<Self, Error> {
+        let mut t = Self::parse_str(arg)?;
+        if t.is_leap_second() {
+            match policy {
+                LeapSecond::Reject => return Err(Error::E("OutOfRangeSecond".to_string())),
+                LeapSecond::Clamp => t.nano = 999_999_999,
+                LeapSecond::Keep => {}
+            }
+        }
+        Ok(t)
+    }
+
+    /// is this time within a leap second, displayed as second 60?
+    /// allowed at any hh:mm:59, since a leap second at 23:59:60 UTC falls on another
+    /// minute of the local time at most offsets, e.g. 08:59:60 at +08:00 or 05:29:60 at +05:30
+    pub fn is_leap_second(&self) -> bool {
+        self.nano > 999_999_999
+    }
+
+    /// parse like `FromStr`, and also accept "24:00", "24:00:00" or "2400" as the end of the day.
+    /// return the time and true when it is the end of the day, which is 00:00 of the next day
+    /// ```rust
+    /// let (t, next_day) = fastdate::Time::parse_end_of_day("24:00").unwrap();
+    /// assert_eq!((t.to_string().as_str(), next_day), ("00:00:00", true));
+    /// ```
+    pub fn parse_end_of_day(arg: &str) -> Result<(Self, bool), Error> {
+        if let Some(rest) = arg.strip_prefix("24") {
+            let t = Self::parse_str(&format!("00{}", rest))?;
+            if t.minute != 0 || t.sec != 0 || t.nano != 0 {
+                return Err(Error::E("OutOfRangeHour".to_string()));
+            }
+            return Ok((t, true));
+        }
+        Ok((Self::parse_str(arg)?, false))
+    }
+
+    /// format in one of the forms `FromStr` accepts
+    /// ```rust
+    /// use fastdate::{Time, TimeStyle};
+    /// let t = Time::new(14, 30, 0, 0).unwrap();
+    /// assert_eq!(t.display_style(TimeStyle::Extended), "14:30:00");
+    /// assert_eq!(t.display_style(TimeStyle::HourMinute), "14:30");
+    /// assert_eq!(t.display_style(TimeStyle::Basic), "143000");
+    /// assert_eq!(t.display_style(TimeStyle::Clock12), "2:30 PM");
+    /// ```
+    pub fn display_style(&self, style: TimeStyle) -> String {
+        match style {
+            TimeStyle::Extended => self.to_string(),
+            TimeStyle::HourMinute => format!("{:02}:{:02}", self.hour, self.minute),
+            TimeStyle::Basic => self.to_string().replace(':', ""),
+            TimeStyle::Clock12 => {
+                let hour = match self.hour % 12 {
+                    0 => 12,
+                    h => h,
+                };
+                let meridiem = if self.hour < 12 { "AM" } else { "PM" };
+                let v = self.to_string();
+                if self.sec == 0 && self.nano == 0 {
+                    format!("{}{} {}", hour, &v[2..5], meridiem)
+                } else {
+                    format!("{}{} {}", hour, &v[2..], meridiem)
+                }
+            }
+        }
+    }
+
+    /// new a time, checks that every component is in range
+    /// ```rust
+    /// assert!(fastdate::Time::new(23, 59, 59, 999_999_999).is_ok());
+    /// assert!(fastdate::Time::new(24, 0, 0, 0).is_err());
+    /// ```
+    pub fn new(hour: u8, minute: u8, sec: u8, nano: u32) -> Result<Self, Error> {
+        let time = Self {
+            nano,
+            sec,
+            minute,
+            hour,
+        };
+        time.check()?;
+        Ok(time)
+    }
+
+    /// is every component in range?
+    /// always true, the fields are private and every constructor checks them
+    pub fn is_valid(&self) -> bool {
+        self.check().is_ok()
+    }
+
+    pub(crate) fn check(&self) -> Result<(), Error> {
+        if self.hour > 23 {
+            return Err(Error::E("OutOfRangeHour".to_string()));
+        }
+        if self.minute > 59 {
+            return Err(Error::E("OutOfRangeMinute".to_string()));
+        }
+        if self.sec > 59 {
+            return Err(Error::E("OutOfRangeSecond".to_string()));
+        }
+        if self.nano > 999_999_999 && (self.sec != 59 || self.nano > 1_999_999_999) {
+            return Err(Error::E("OutOfRangeNano".to_string()));
+        }
+        Ok(())
+    }
+
+    /// set nano, or an error when out of 0...999999999 (0...1999999999 when sec is 59)
+    pub fn with_nano(mut self, arg: u32) -> Result<Self, Error> {
         self.nano = arg;
-        self
+        self.check()?;
+        Ok(self)
     }
 
-    /// 0...999999
-    pub fn set_micro(mut self, arg: u32) -> Self {
-        self.nano = arg * 1000;
-        self
+    /// set micro, or an error when out of 0...999999
+    pub fn with_micro(self, arg: u32) -> Result<Self, Error> {
+        self.with_nano(arg.saturating_mul(1000))
     }
-    /// 0...59
-    pub fn set_sec(mut self, arg: u8) -> Self {
+
+    /// set sec, or an error when out of 0...59, a leap second becomes an ordinary second
+    pub fn with_sec(mut self, arg: u8) -> Result<Self, Error> {
         self.sec = arg;
-        self
+        self.nano %= 1_000_000_000;
+        self.check()?;
+        Ok(self)
     }
-    /// 0...59
-    pub fn set_minute(mut self, arg: u8) -> Self {
+
+    /// set minute, or an error when out of 0...59
+    pub fn with_minute(mut self, arg: u8) -> Result<Self, Error> {
         self.minute = arg;
-        self
+        self.check()?;
+        Ok(self)
     }
-    /// 0...23
-    pub fn set_hour(mut self, arg: u8) -> Self {
+
+    /// set hour, or an error when out of 0...23
+    pub fn with_hour(mut self, arg: u8) -> Result<Self, Error> {
         self.hour = arg;
-        self
+        self.check()?;
+        Ok(self)
+    }
+
+    /// 0...999999999, ignored when out of range
+    pub fn set_nano(self, arg: u32) -> Self {
+        self.clone().with_nano(arg).unwrap_or(self)
+    }
+
+    /// 0...999999, ignored when out of range
+    pub fn set_micro(self, arg: u32) -> Self {
+        self.clone().with_micro(arg).unwrap_or(self)
+    }
+    /// 0...59, ignored when out of range
+    pub fn set_sec(self, arg: u8) -> Self {
+        self.clone().with_sec(arg).unwrap_or(self)
+    }
+    /// 0...59, ignored when out of range
+    pub fn set_minute(self, arg: u8) -> Self {
+        self.clone().with_minute(arg).unwrap_or(self)
+    }
+    /// 0...23, ignored when out of range
+    pub fn set_hour(self, arg: u8) -> Self {
+        self.clone().with_hour(arg).unwrap_or(self)
+    }
+    /// nanoseconds since midnight
+    pub(crate) fn nanos_of_day(&self) -> u64 {
+        (self.hour as u64 * 3600 + self.minute as u64 * 60 + self.sec as u64) * 1_000_000_000
+            + self.nano as u64
+    }
+
+    /// time from nanoseconds since midnight, must be less than NANOS_PER_DAY
+    pub(crate) fn from_nanos_of_day(nanos: u64) -> Self {
+        let secs = nanos / 1_000_000_000;
+        Self {
+            nano: (nanos % 1_000_000_000) as u32,
+            sec: (secs % 60) as u8,
+            minute: (secs / 60 % 60) as u8,
+            hour: (secs / 3600) as u8,
+        }
+    }
+
+    /// add Duration, return the time of day and the number of days carried over midnight
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use std::time::Duration;
+    /// let t = fastdate::Time::from_str("23:00:00").unwrap();
+    /// let (t, days) = t.overflowing_add(Duration::from_secs(2 * 3600));
+    /// assert_eq!((t.to_string().as_str(), days), ("01:00:00", 1));
+    /// ```
+    pub fn overflowing_add(&self, d: Duration) -> (Self, u64) {
+        let nanos = self.nanos_of_day() as u128 + d.as_nanos();
+        (
+            Self::from_nanos_of_day((nanos % NANOS_PER_DAY as u128) as u64),
+            (nanos / NANOS_PER_DAY as u128) as u64,
+        )
+    }
+
+    /// sub Duration, return the time of day and the number of days borrowed over midnight
+    pub fn overflowing_sub(&self, d: Duration) -> (Self, u64) {
+        let nanos = self.nanos_of_day() as u128;
+        let d = d.as_nanos();
+        if d <= nanos {
+            return (Self::from_nanos_of_day((nanos - d) as u64), 0);
+        }
+        let days = (d - nanos).div_ceil(NANOS_PER_DAY as u128);
+        (
+            Self::from_nanos_of_day((nanos + days * NANOS_PER_DAY as u128 - d) as u64),
+            days as u64,
+        )
+    }
+
+    /// add Duration, wrapping around midnight
+    pub fn wrapping_add(&self, d: Duration) -> Self {
+        self.overflowing_add(d).0
+    }
+
+    /// sub Duration, wrapping around midnight
+    pub fn wrapping_sub(&self, d: Duration) -> Self {
+        self.overflowing_sub(d).0
+    }
+
+    /// align down to a multiple of unit.
+    /// a leap second aligns as the second before it, results wrap around midnight
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use std::time::Duration;
+    /// use fastdate::{Time, TimeUnit};
+    /// let t = Time::from_str("11:12:14.5").unwrap();
+    /// assert_eq!(t.truncate(TimeUnit::Second).to_string(), "11:12:14");
+    /// let unit = TimeUnit::every(Duration::from_secs(5 * 60));
+    /// assert_eq!(t.truncate(unit).to_string(), "11:10:00");
+    /// ```
+    pub fn truncate(&self, unit: TimeUnit) -> Self {
+        self.align(unit, Align::Floor)
+    }
+
+    /// align up to a multiple of unit, "23:30" is "00:00" with `TimeUnit::Hour`
+    pub fn ceil(&self, unit: TimeUnit) -> Self {
+        self.align(unit, Align::Ceil)
+    }
+
+    /// align to the nearest multiple of unit, ties are broken by mode
+    pub fn round(&self, unit: TimeUnit, mode: RoundMode) -> Self {
+        self.align(unit, Align::Round(mode))
     }
+
+    fn align(&self, unit: TimeUnit, align: Align) -> Self {
+        let mut nanos = self.nanos_of_day() as i128;
+        if self.is_leap_second() {
+            nanos -= 1_000_000_000;
+        }
+        let v = align_nanos(nanos, unit, align);
+        Self::from_nanos_of_day(v.rem_euclid(NANOS_PER_DAY as i128) as u64)
+    }
+
     /// get nano
     pub fn get_nano(&self) -> u32 {
         self.nano
@@ -157,26 +468,33 @@ impl Time {
         self.hour
     }
 
-    /// display time and return len
+    /// display time and return len, a leap second is displayed as second 60
     pub fn display_time(&self, start: usize, buf: &mut [u8]) -> usize {
+        let (sec, nano) = if self.is_leap_second() {
+            (self.sec + 1, self.nano - 1_000_000_000)
+        } else {
+            (self.sec, self.nano)
+        };
         buf[start] = b'0' + (self.hour / 10);
         buf[start + 1] = b'0' + (self.hour % 10);
+        buf[start + 2] = b':';
         buf[start + 3] = b'0' + (self.minute / 10);
         buf[start + 4] = b'0' + (self.minute % 10);
-        buf[start + 6] = b'0' + (self.sec / 10);
-        buf[start + 7] = b'0' + (self.sec % 10);
+        buf[start + 5] = b':';
+        buf[start + 6] = b'0' + (sec / 10);
+        buf[start + 7] = b'0' + (sec % 10);
         let mut real_len = start + 1 + 8 + 8 + 1;
         buf[start + 8] = b'.';
-        buf[start + 9] = b'0' + (self.nano / 100000000 % 10) as u8;
-        buf[start + 10] = b'0' + (self.nano / 10000000 % 10) as u8;
-        buf[start + 11] = b'0' + (self.nano / 1000000 % 10) as u8;
-        buf[start + 12] = b'0' + (self.nano / 100000 % 10) as u8;
-        buf[start + 13] = b'0' + (self.nano / 10000 % 10) as u8;
-        buf[start + 14] = b'0' + (self.nano / 1000 % 10) as u8;
-        buf[start + 15] = b'0' + (self.nano / 100 % 10) as u8;
-        buf[start + 16] = b'0' + (self.nano / 10 % 10) as u8;
-        buf[start + 17] = b'0' + (self.nano % 10) as u8;
-        if self.nano == 0 {
+        buf[start + 9] = b'0' + (nano / 100000000 % 10) as u8;
+        buf[start + 10] = b'0' + (nano / 10000000 % 10) as u8;
+        buf[start + 11] = b'0' + (nano / 1000000 % 10) as u8;
+        buf[start + 12] = b'0' + (nano / 100000 % 10) as u8;
+        buf[start + 13] = b'0' + (nano / 10000 % 10) as u8;
+        buf[start + 14] = b'0' + (nano / 1000 % 10) as u8;
+        buf[start + 15] = b'0' + (nano / 100 % 10) as u8;
+        buf[start + 16] = b'0' + (nano / 10 % 10) as u8;
+        buf[start + 17] = b'0' + (nano % 10) as u8;
+        if nano == 0 {
             real_len -= 10;
         } else {
             let current = real_len;
@@ -197,21 +515,15 @@ impl Time {
     }
 }
 
-impl From<Duration> for Time {
-    fn from(d: Duration) -> Self {
-        let hour = (d.as_secs() / 3600) as u8;
-        let min = (d.as_secs() / 60 - (hour as u64 * 60)) as u8;
-        let sec = (d.as_secs() - hour as u64 * 3600u64 - min as u64 * 60u64) as u8;
-        let micros = d
-            - Duration::from_secs(hour as u64 * 3600u64)
-            - Duration::from_secs(min as u64 * 60u64)
-            - Duration::from_secs(sec as u64);
-        Self {
-            nano: micros.as_nanos() as u32,
-            sec,
-            minute: min,
-            hour,
+/// an error for durations of 24 hours or more, see `Time::overflowing_add` to wrap them
+impl TryFrom<Duration> for Time {
+    type Error = Error;
+
+    fn try_from(d: Duration) -> Result<Self, Self::Error> {
+        if d.as_nanos() >= NANOS_PER_DAY as u128 {
+            return Err(Error::E("OutOfRangeDuration".to_string()));
         }
+        Ok(Self::from_nanos_of_day(d.as_nanos() as u64))
     }
 }
 
@@ -224,13 +536,178 @@ impl From<Time> for Duration {
     }
 }
 
+impl Ord for Time {
+    fn cmp(&self, other: &Time) -> cmp::Ordering {
+        (self.hour, self.minute, self.sec, self.nano).cmp(&(
+            other.hour,
+            other.minute,
+            other.sec,
+            other.nano,
+        ))
+    }
+}
+
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Time) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// signed difference of two times of the same day
+impl Sub<Time> for Time {
+    type Output = SignedDuration;
+
+    fn sub(self, rhs: Time) -> Self::Output {
+        SignedDuration::nanoseconds(self.nanos_of_day() as i64 - rhs.nanos_of_day() as i64)
+    }
+}
+
+/// split "2:30 PM", "2:30 a.m." (any case) into "2:30" and is pm
+fn split_meridiem(arg: &str) -> Option<(&str, bool)> {
+    const SUFFIX: [(&str, bool); 6] = [
+        ("a.m.", false),
+        ("p.m.", true),
+        ("a.m", false),
+        ("p.m", true),
+        ("am", false),
+        ("pm", true),
+    ];
+    let bytes = arg.as_bytes();
+    for (suffix, pm) in SUFFIX {
+        if bytes.len() >= suffix.len()
+            && bytes[bytes.len() - suffix.len()..].eq_ignore_ascii_case(suffix.as_bytes())
+        {
+            return Some((arg[..arg.len() - suffix.len()].trim_end(), pm));
+        }
+    }
+    None
+}
+
+/// What parsing does with a leap second, "23:59:60"
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum LeapSecond {
+    /// an OutOfRangeSecond error
+    Reject,
+    /// "23:59:59.999999999", the last nanosecond before it
+    Clamp,
+    /// held as nano >= 1_000_000_000 of second 59 and displayed as second 60
+    #[default]
+    Keep,
+}
+
+/// The unit `truncate`, `round` and `ceil` align a `Time` or `DateTime` to
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TimeUnit {
+    Nano,
+    Micro,
+    Milli,
+    Second,
+    Minute,
+    Hour,
+    /// midnight at the offset of the value
+    Day,
+    /// multiples of step from origin, origin is measured from midnight
+    /// (of 1970-01-01 at the offset of a `DateTime`),
+    /// `Every { step: 15 minutes, origin: 5 minutes }` aligns to :05, :20, :35 and :50
+    Every {
+        step: Duration,
+        origin: Duration,
+    },
+}
+
+impl TimeUnit {
+    /// multiples of step, `TimeUnit::Every` with a zero step is one nanosecond
+    pub fn every(step: Duration) -> Self {
+        TimeUnit::Every {
+            step,
+            origin: Duration::ZERO,
+        }
+    }
+
+    /// (step, origin) in nanoseconds
+    fn step_nanos(&self) -> (i128, i128) {
+        let step: i128 = match self {
+            TimeUnit::Nano => 1,
+            TimeUnit::Micro => 1_000,
+            TimeUnit::Milli => 1_000_000,
+            TimeUnit::Second => 1_000_000_000,
+            TimeUnit::Minute => 60_000_000_000,
+            TimeUnit::Hour => 3_600_000_000_000,
+            TimeUnit::Day => NANOS_PER_DAY as i128,
+            TimeUnit::Every { step, .. } => step.as_nanos().max(1) as i128,
+        };
+        match self {
+            TimeUnit::Every { origin, .. } => (step, origin.as_nanos() as i128 % step),
+            _ => (step, 0),
+        }
+    }
+}
+
+/// How `round` breaks a tie, a value exactly half way between two multiples
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum RoundMode {
+    /// the later multiple
+    #[default]
+    HalfUp,
+    /// the earlier multiple
+    HalfDown,
+    /// the even multiple
+    HalfEven,
+}
+
+/// how `align_nanos` moves a value onto a multiple
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Align {
+    Floor,
+    Ceil,
+    Round(RoundMode),
+}
+
+/// align nanos to a multiple of unit
+pub(crate) fn align_nanos(nanos: i128, unit: TimeUnit, align: Align) -> i128 {
+    let (step, origin) = unit.step_nanos();
+    let v = nanos - origin;
+    let floor = v.div_euclid(step) * step;
+    let rem = v - floor;
+    let up = match align {
+        Align::Floor => false,
+        Align::Ceil => rem != 0,
+        Align::Round(mode) => match (rem * 2).cmp(&step) {
+            cmp::Ordering::Less => false,
+            cmp::Ordering::Greater => true,
+            cmp::Ordering::Equal => match mode {
+                RoundMode::HalfUp => true,
+                RoundMode::HalfDown => false,
+                RoundMode::HalfEven => (floor / step) % 2 != 0,
+            },
+        },
+    };
+    origin + floor + if up { step } else { 0 }
+}
+
+/// The forms a `Time` can be formatted in, see `Time::display_style`
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TimeStyle {
+    /// "14:30:00.5", same as `Display`
+    Extended,
+    /// "14:30", the seconds are dropped
+    HourMinute,
+    /// "143000.5"
+    Basic,
+    /// "2:30 PM", or "2:30:15.5 PM" when there are seconds
+    Clock12,
+}
+
 impl FromStr for Time {
     type Err = Error;
 
-    /// from RFC3339Micro = "15:04:05.999999999"
+    /// from RFC3339Micro = "15:04:05.999999999", a leap second "23:59:60" is kept,
+    /// see `Time::from_str_leap`
+    /// or "15:04", the basic format "150405.999999999" or "1504",
+    /// or a 12-hour clock "3:04 PM", "3:04:05 p.m." (any case)
     fn from_str(s: &str) -> Result<Time, Error> {
         //"00:00:00.000000";
-        Ok(Time::parse_bytes_partial(s.as_bytes(), 0)?.0)
+        Time::parse_str(s)
     }
 }
 
@@ -243,23 +720,143 @@ impl Display for Time {
     }
 }
 
+impl Time {
+    /// the compact binary form, nanoseconds since midnight, or for a leap second
+    /// 86400000000000 + the minute of the day in seconds + the nanos past second 60
+    fn to_compact(&self) -> u64 {
+        match self.is_leap_second() {
+            true => {
+                NANOS_PER_DAY
+                    + (self.hour as u64 * 60 + self.minute as u64) * 1_000_000_000
+                    + (self.nano - 1_000_000_000) as u64
+            }
+            false => self.nanos_of_day(),
+        }
+    }
+
+    /// from the compact binary form, see `to_compact`
+    fn from_compact(v: u64) -> Result<Self, Error> {
+        if v < NANOS_PER_DAY {
+            return Ok(Self::from_nanos_of_day(v));
+        }
+        let minutes = (v - NANOS_PER_DAY) / 1_000_000_000;
+        if minutes >= 24 * 60 {
+            return Err(Error::E("OutOfRangeNano".to_string()));
+        }
+        Ok(Self {
+            nano: ((v - NANOS_PER_DAY) % 1_000_000_000) as u32 + 1_000_000_000,
+            sec: 59,
+            minute: (minutes % 60) as u8,
+            hour: (minutes / 60) as u8,
+        })
+    }
+}
+
 impl Serialize for Time {
+    /// a time string, or for formats that are not human readable the u64 nanoseconds
+    /// since midnight, a leap second is 86400000000000 + the minute of the day in seconds
+    /// + the nanos past second 60. Both forms are stable across versions.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_u64(self.to_compact());
+        }
         serializer.serialize_str(&self.to_string())
     }
 }
 
 impl<'de> Deserialize<'de> for Time {
+    /// a time string or its bytes,
+    /// or the u64 written for formats that are not human readable,
+    /// see `fastdate::serde::Lenient` for numbers and maps
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        use serde::de::Error;
-        Time::from_str(&String::deserialize(deserializer)?)
-            .map_err(|e| Error::custom(e.to_string()))
+        if !deserializer.is_human_readable() {
+            return Time::from_compact(u64::deserialize(deserializer)?)
+                .map_err(|e| de::Error::custom(e.to_string()));
+        }
+        deserializer.deserialize_str(TimeVisitor { lenient: false })
+    }
+}
+
+/// reads a Time from a string or bytes, and when lenient from seconds since midnight
+/// (an integer or a float) or a map `{"secs", "nanos"}`
+pub(crate) struct TimeVisitor {
+    pub(crate) lenient: bool,
+}
+
+impl TimeVisitor {
+    fn at_secs<E: de::Error>(&self, secs: u64, nanos: u64) -> Result<Time, E> {
+        if secs >= 86400 || nanos > 999_999_999 {
+            return Err(E::custom("OutOfRangeTime"));
+        }
+        Ok(Time::from_nanos_of_day(secs * 1_000_000_000 + nanos))
+    }
+}
+
+impl<'de> Visitor<'de> for TimeVisitor {
+    type Value = Time;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.lenient {
+            true => {
+                f.write_str("a time string, seconds since midnight or a map with secs and nanos")
+            }
+            false => f.write_str("a time string"),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Time, E> {
+        Time::from_str(v).map_err(|e| E::custom(e.to_string()))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Time, E> {
+        let v = std::str::from_utf8(v)
+            .map_err(|_| E::invalid_value(de::Unexpected::Bytes(v), &self))?;
+        self.visit_str(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Time, E> {
+        let secs =
+            u64::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))?;
+        self.visit_u64(secs)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Time, E> {
+        if !self.lenient {
+            return Err(E::invalid_type(de::Unexpected::Unsigned(v), &self));
+        }
+        self.at_secs(v, 0)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Time, E> {
+        if !self.lenient {
+            return Err(E::invalid_type(de::Unexpected::Float(v), &self));
+        }
+        if !(0.0..86400.0).contains(&v) {
+            return Err(E::invalid_value(de::Unexpected::Float(v), &self));
+        }
+        let nanos = ((v * 1e9).round() as u64).min(NANOS_PER_DAY - 1);
+        Ok(Time::from_nanos_of_day(nanos))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Time, A::Error> {
+        if !self.lenient {
+            return Err(de::Error::invalid_type(de::Unexpected::Map, &self));
+        }
+        let (mut secs, mut nanos) = (None, 0u64);
+        while let Some(key) = map.next_key::<SecsNanos>()? {
+            match key {
+                SecsNanos::Secs => secs = Some(map.next_value::<u64>()?),
+                SecsNanos::Nanos => nanos = map.next_value()?,
+            }
+        }
+        let secs = secs.ok_or_else(|| de::Error::missing_field("secs"))?;
+        self.at_secs(secs, nanos)
     }
 }
 
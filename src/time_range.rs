@@ -0,0 +1,203 @@
+use crate::error::Error;
+use crate::time::NANOS_PER_DAY;
+use crate::{DateTime, Time, TimeStyle};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// A range of the time of day, `[start, end)`, such as opening hours.
+///
+/// When end is before start the range crosses midnight, "22:00-06:00" is 8 hours,
+/// when end equals start the range is the whole day.
+///
+/// Parse using `FromStr` impl, "22:00-06:00".
+/// Format using the `Display` trait.
+/// ```rust
+/// use std::str::FromStr;
+/// use fastdate::{Time, TimeRange};
+/// let night = TimeRange::from_str("22:00-06:00").unwrap();
+/// assert!(night.contains(&Time::from_str("23:30").unwrap()));
+/// assert!(night.contains(&Time::from_str("05:59").unwrap()));
+/// assert!(!night.contains(&Time::from_str("06:00").unwrap()));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TimeRange {
+    pub start: Time,
+    pub end: Time,
+}
+
+impl TimeRange {
+    /// new a time range, checks that start and end are valid times
+    pub fn new(start: Time, end: Time) -> Result<Self, Error> {
+        start.check()?;
+        end.check()?;
+        Ok(Self { start, end })
+    }
+
+    /// the whole day, "00:00-00:00"
+    pub fn whole_day() -> Self {
+        Self {
+            start: Time::from_nanos_of_day(0),
+            end: Time::from_nanos_of_day(0),
+        }
+    }
+
+    /// is end before start?
+    pub fn crosses_midnight(&self) -> bool {
+        nanos(&self.end) < nanos(&self.start)
+    }
+
+    /// is end equal to start?
+    pub fn is_whole_day(&self) -> bool {
+        nanos(&self.end) == nanos(&self.start)
+    }
+
+    /// is time within the range? a leap second counts as the second before it
+    pub fn contains(&self, time: &Time) -> bool {
+        let v = nanos(time);
+        self.segments()
+            .iter()
+            .any(|(start, end)| (*start..*end).contains(&v))
+    }
+
+    /// is the time of day of a DateTime, at its own offset, within the range?
+    pub fn contains_datetime(&self, datetime: &DateTime) -> bool {
+        self.contains(&Time::from(datetime.clone()))
+    }
+
+    /// length of the range, 24 hours for the whole day
+    pub fn duration(&self) -> Duration {
+        let len: u64 = self.segments().iter().map(|(start, end)| end - start).sum();
+        Duration::from_nanos(len)
+    }
+
+    /// do the ranges share any time?
+    pub fn overlaps(&self, other: &TimeRange) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// the time in both ranges, at most two ranges,
+    /// "22:00-06:00" and "05:00-23:00" share "05:00-06:00" and "22:00-23:00"
+    pub fn intersection(&self, other: &TimeRange) -> Vec<TimeRange> {
+        let mut v = Vec::new();
+        for (s1, e1) in self.segments() {
+            for (s2, e2) in other.segments() {
+                let (start, end) = (s1.max(s2), e1.min(e2));
+                if start < end {
+                    v.push((start, end));
+                }
+            }
+        }
+        to_ranges(v)
+    }
+
+    /// the time in either range, `None` when they neither overlap nor touch
+    pub fn union(&self, other: &TimeRange) -> Option<TimeRange> {
+        let mut v = self.segments();
+        v.extend(other.segments());
+        let mut ranges = to_ranges(v);
+        if ranges.len() == 1 {
+            ranges.pop()
+        } else {
+            None
+        }
+    }
+
+    /// the range as segments of nanoseconds since midnight, `[start, end)` within one day
+    fn segments(&self) -> Vec<(u64, u64)> {
+        let (start, end) = (nanos(&self.start), nanos(&self.end));
+        if start < end {
+            vec![(start, end)]
+        } else if start == end {
+            vec![(0, NANOS_PER_DAY)]
+        } else if end == 0 {
+            vec![(start, NANOS_PER_DAY)]
+        } else {
+            vec![(0, end), (start, NANOS_PER_DAY)]
+        }
+    }
+}
+
+/// nanoseconds since midnight, a leap second counts as the second before it
+fn nanos(time: &Time) -> u64 {
+    let leap = u64::from(time.is_leap_second()) * 1_000_000_000;
+    time.nanos_of_day() - leap
+}
+
+/// merge segments into ranges, joining a segment ending at midnight with one starting at it
+fn to_ranges(mut v: Vec<(u64, u64)>) -> Vec<TimeRange> {
+    v.sort();
+    let mut merged: Vec<(u64, u64)> = Vec::new();
+    for (start, end) in v {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    if merged.len() > 1 && merged[0].0 == 0 && merged[merged.len() - 1].1 == NANOS_PER_DAY {
+        let first = merged.remove(0);
+        let last = merged.len() - 1;
+        merged[last].1 = first.1;
+    }
+    merged
+        .into_iter()
+        .map(|(start, end)| TimeRange {
+            start: Time::from_nanos_of_day(start % NANOS_PER_DAY),
+            end: Time::from_nanos_of_day(end % NANOS_PER_DAY),
+        })
+        .collect()
+}
+
+impl FromStr for TimeRange {
+    type Err = Error;
+
+    /// "22:00-06:00", each side is parsed by `Time::from_str`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| Error::from("MissingRangeSeparator"))?;
+        Self::new(Time::from_str(start.trim())?, Time::from_str(end.trim())?)
+    }
+}
+
+impl Display for TimeRange {
+    /// "22:00-06:00", seconds are written when either side has them
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let style = if self.start.sec == 0
+            && self.start.nano == 0
+            && self.end.sec == 0
+            && self.end.nano == 0
+        {
+            TimeStyle::HourMinute
+        } else {
+            TimeStyle::Extended
+        };
+        write!(
+            f,
+            "{}-{}",
+            self.start.display_style(style),
+            self.end.display_style(style)
+        )
+    }
+}
+
+impl Serialize for TimeRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        TimeRange::from_str(&String::deserialize(deserializer)?)
+            .map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
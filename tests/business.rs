@@ -0,0 +1,149 @@
+use fastdate::{BusinessCalendar, Date, Roll};
+use std::str::FromStr;
+
+fn date(s: &str) -> Date {
+    Date::from_str(s).unwrap()
+}
+
+fn calendar() -> BusinessCalendar {
+    BusinessCalendar::new()
+        .add_holiday(date("2022-12-26"))
+        .add_holiday(date("2023-01-02"))
+}
+
+#[test]
+fn test_week_day() {
+    assert_eq!(date("2022-12-12").week_day(), 1);
+    assert_eq!(date("2022-12-18").week_day(), 7);
+    assert_eq!(date("1970-01-01").week_day(), 4);
+    assert_eq!(date("1600-03-01").week_day(), 3);
+}
+
+#[test]
+fn test_is_business_day() {
+    let cal = calendar();
+    assert!(cal.is_business_day(&date("2022-12-23")));
+    assert!(!cal.is_business_day(&date("2022-12-24")));
+    assert!(!cal.is_business_day(&date("2022-12-25")));
+    assert!(!cal.is_business_day(&date("2022-12-26")));
+    assert!(cal.is_business_day(&date("2022-12-27")));
+}
+
+#[test]
+fn test_set_weekend() {
+    let cal = BusinessCalendar::new().set_weekend(&[5, 6]).unwrap();
+    assert_eq!(cal.weekend(), vec![5, 6]);
+    assert!(!cal.is_business_day(&date("2022-12-23")));
+    assert!(cal.is_business_day(&date("2022-12-25")));
+
+    assert!(
+        BusinessCalendar::new()
+            .set_weekend(&[1, 2, 3, 4, 5, 6, 7])
+            .is_err()
+    );
+    assert!(BusinessCalendar::new().set_weekend(&[0]).is_err());
+    assert!(BusinessCalendar::new().set_weekend(&[8]).is_err());
+}
+
+#[test]
+fn test_add_business_days() {
+    let cal = calendar();
+    assert_eq!(
+        cal.add_business_days(&date("2022-12-22"), 2).to_string(),
+        "2022-12-27"
+    );
+    assert_eq!(
+        cal.add_business_days(&date("2022-12-30"), 1).to_string(),
+        "2023-01-03"
+    );
+    assert_eq!(
+        cal.add_business_days(&date("2022-12-27"), -1).to_string(),
+        "2022-12-23"
+    );
+    assert_eq!(
+        cal.add_business_days(&date("2022-12-24"), 0).to_string(),
+        "2022-12-24"
+    );
+}
+
+#[test]
+fn test_business_days_between() {
+    let cal = calendar();
+    assert_eq!(
+        cal.business_days_between(&date("2022-12-19"), &date("2023-01-09")),
+        13
+    );
+    assert_eq!(
+        cal.business_days_between(&date("2023-01-09"), &date("2022-12-19")),
+        -13
+    );
+    assert_eq!(
+        cal.business_days_between(&date("2022-12-24"), &date("2022-12-27")),
+        0
+    );
+    let start = date("2022-11-03");
+    for n in 0..40 {
+        let end = cal.add_business_days(&start, n);
+        assert_eq!(cal.business_days_between(&start, &end), n);
+    }
+}
+
+#[test]
+fn test_roll() {
+    let cal = calendar();
+    assert_eq!(
+        cal.roll(&date("2022-12-24"), Roll::Following).to_string(),
+        "2022-12-27"
+    );
+    assert_eq!(
+        cal.roll(&date("2022-12-26"), Roll::Preceding).to_string(),
+        "2022-12-23"
+    );
+    assert_eq!(
+        cal.roll(&date("2022-12-23"), Roll::Preceding).to_string(),
+        "2022-12-23"
+    );
+    assert_eq!(
+        cal.roll(&date("2022-12-31"), Roll::ModifiedFollowing)
+            .to_string(),
+        "2022-12-30"
+    );
+    assert_eq!(
+        cal.roll(&date("2022-12-24"), Roll::ModifiedFollowing)
+            .to_string(),
+        "2022-12-27"
+    );
+}
+
+#[test]
+fn test_from_str_display() {
+    let cal = BusinessCalendar::from_str(
+        "
+        # test market
+        weekend=5,6
+        2023-01-02
+        2022-12-26
+        ",
+    )
+    .unwrap();
+    assert_eq!(cal.weekend(), vec![5, 6]);
+    assert!(cal.is_holiday(&date("2022-12-26")));
+    assert_eq!(cal.to_string(), "weekend=5,6\n2022-12-26\n2023-01-02");
+    assert_eq!(BusinessCalendar::from_str(&cal.to_string()).unwrap(), cal);
+}
+
+#[test]
+fn test_from_str_error() {
+    assert!(BusinessCalendar::from_str("weekend=8").is_err());
+    assert!(BusinessCalendar::from_str("weekend=1,2,3,4,5,6,7").is_err());
+    assert!(BusinessCalendar::from_str("2022-13-01").is_err());
+}
+
+#[test]
+fn test_ser_de() {
+    let cal = calendar();
+    let js = serde_json::to_string(&cal).unwrap();
+    assert_eq!(js, "\"weekend=6,7\\n2022-12-26\\n2023-01-02\"");
+    let r: BusinessCalendar = serde_json::from_str(&js).unwrap();
+    assert_eq!(r, cal);
+}
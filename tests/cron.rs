@@ -0,0 +1,241 @@
+use fastdate::{Cron, DateTime};
+use std::str::FromStr;
+
+fn next(cron: &str, after: &str) -> Option<String> {
+    Cron::from_str(cron)
+        .unwrap()
+        .next_after(&DateTime::from_str(after).unwrap())
+        .map(|v| v.to_string())
+}
+
+fn prev(cron: &str, before: &str) -> Option<String> {
+    Cron::from_str(cron)
+        .unwrap()
+        .prev_before(&DateTime::from_str(before).unwrap())
+        .map(|v| v.to_string())
+}
+
+#[test]
+fn test_from_str() {
+    let v = Cron::from_str(" */15  9-17 * * MON-FRI ").unwrap();
+    assert_eq!(v.to_string(), "*/15 9-17 * * MON-FRI");
+    assert!(Cron::from_str("0 0 12 L * ?").is_ok());
+    assert!(Cron::from_str("0 0 12 ? * 5#3").is_ok());
+    assert!(Cron::from_str("@daily").is_ok());
+    assert!(Cron::from_str("@reboot").is_err());
+    assert!(Cron::from_str("* * * *").is_err());
+    assert!(Cron::from_str("* * * * * * *").is_err());
+    assert!(Cron::from_str("60 * * * *").is_err());
+    assert!(Cron::from_str("* 24 * * *").is_err());
+    assert!(Cron::from_str("* * 0 * *").is_err());
+    assert!(Cron::from_str("* * * 13 *").is_err());
+    assert!(Cron::from_str("* * * * 8").is_err());
+    assert!(Cron::from_str("*/0 * * * *").is_err());
+    assert!(Cron::from_str("5-1 * * * *").is_err());
+    assert!(Cron::from_str("* * * * 5#6").is_err());
+    assert!(Cron::from_str("* * 32W * *").is_err());
+}
+
+#[test]
+fn test_next_after() {
+    assert_eq!(
+        next("*/15 * * * *", "2022-12-16T10:07:30Z").unwrap(),
+        "2022-12-16T10:15:00Z"
+    );
+    // strictly after
+    assert_eq!(
+        next("*/15 * * * *", "2022-12-16T10:15:00Z").unwrap(),
+        "2022-12-16T10:30:00Z"
+    );
+    assert_eq!(
+        next("*/15 * * * *", "2022-12-16T10:14:59.5Z").unwrap(),
+        "2022-12-16T10:15:00Z"
+    );
+    assert_eq!(
+        next("30 */10 * * * *", "2022-12-16T23:55:00+08:00").unwrap(),
+        "2022-12-17T00:00:30+08:00"
+    );
+    assert_eq!(
+        next("0 9 * * MON-FRI", "2022-12-16T10:00:00+08:00").unwrap(),
+        "2022-12-19T09:00:00+08:00"
+    );
+    assert_eq!(
+        next("0 0 1 JAN,JUL *", "2022-12-16T10:00:00Z").unwrap(),
+        "2023-01-01T00:00:00Z"
+    );
+    assert_eq!(
+        next("@hourly", "2022-12-31T23:30:00Z").unwrap(),
+        "2023-01-01T00:00:00Z"
+    );
+    assert_eq!(
+        next("@weekly", "2022-12-16T10:00:00Z").unwrap(),
+        "2022-12-18T00:00:00Z"
+    );
+    // Sunday as 0 and 7
+    assert_eq!(
+        next("0 0 * * 7", "2022-12-16T10:00:00Z"),
+        next("0 0 * * 0", "2022-12-16T10:00:00Z")
+    );
+    assert_eq!(
+        next("0 0 29 2 *", "2022-12-16T10:00:00Z").unwrap(),
+        "2024-02-29T00:00:00Z"
+    );
+    assert_eq!(next("0 0 30 2 *", "2022-12-16T10:00:00Z"), None);
+}
+
+#[test]
+fn test_month_day_or_week_day() {
+    // either the 13th or a Friday
+    assert_eq!(
+        next("0 0 13 * FRI", "2022-12-10T00:00:00Z").unwrap(),
+        "2022-12-13T00:00:00Z"
+    );
+    assert_eq!(
+        next("0 0 13 * FRI", "2022-12-13T00:00:00Z").unwrap(),
+        "2022-12-16T00:00:00Z"
+    );
+    // only Fridays
+    assert_eq!(
+        next("0 0 ? * FRI", "2022-12-10T00:00:00Z").unwrap(),
+        "2022-12-16T00:00:00Z"
+    );
+    // a step starting with `*` still restricts the days
+    assert_eq!(
+        next("0 0 */2 * *", "2022-12-01T00:00:00Z").unwrap(),
+        "2022-12-03T00:00:00Z"
+    );
+    assert_eq!(
+        next("0 0 */2 * *", "2022-12-31T00:00:00Z").unwrap(),
+        "2023-01-01T00:00:00Z"
+    );
+    // Sunday, Tuesday, Thursday and Saturday, 2022-12-01 is a Thursday
+    assert_eq!(
+        next("0 0 * * */2", "2022-12-01T00:00:00Z").unwrap(),
+        "2022-12-03T00:00:00Z"
+    );
+    // both must match, a Monday the 1st, 11th, 21st or 31st
+    assert_eq!(
+        next("0 0 */10 * MON", "2022-11-01T00:00:00Z").unwrap(),
+        "2022-11-21T00:00:00Z"
+    );
+    assert_eq!(
+        next("0 0 */10 * MON", "2022-11-21T00:00:00Z").unwrap(),
+        "2023-05-01T00:00:00Z"
+    );
+}
+
+#[test]
+fn test_week_day_range_to_sunday() {
+    assert_eq!(
+        next("0 0 * * SAT-SUN", "2022-12-01T00:00:00Z").unwrap(),
+        "2022-12-03T00:00:00Z"
+    );
+    assert_eq!(
+        next("0 0 * * SAT-SUN", "2022-12-03T00:00:00Z").unwrap(),
+        "2022-12-04T00:00:00Z"
+    );
+    assert_eq!(
+        next("0 0 * * 5-0", "2022-12-04T00:00:00Z").unwrap(),
+        "2022-12-09T00:00:00Z"
+    );
+    assert_eq!(
+        next("0 0 * * SUN-SUN", "2022-12-01T00:00:00Z").unwrap(),
+        "2022-12-04T00:00:00Z"
+    );
+}
+
+#[test]
+fn test_extensions() {
+    assert_eq!(
+        next("0 0 L * *", "2023-02-01T00:00:00Z").unwrap(),
+        "2023-02-28T00:00:00Z"
+    );
+    assert_eq!(
+        next("0 0 L-2 * *", "2023-02-01T00:00:00Z").unwrap(),
+        "2023-02-26T00:00:00Z"
+    );
+    // 2022-10-15 is a Saturday, 2022-10-01 too
+    assert_eq!(
+        next("0 0 15W * *", "2022-10-01T00:00:00Z").unwrap(),
+        "2022-10-14T00:00:00Z"
+    );
+    assert_eq!(
+        next("0 0 1W * *", "2022-09-30T00:00:00Z").unwrap(),
+        "2022-10-03T00:00:00Z"
+    );
+    // 2022-07-31 is a Sunday
+    assert_eq!(
+        next("0 0 LW * *", "2022-07-01T00:00:00Z").unwrap(),
+        "2022-07-29T00:00:00Z"
+    );
+    assert_eq!(
+        next("0 0 * * 5L", "2022-12-01T00:00:00Z").unwrap(),
+        "2022-12-30T00:00:00Z"
+    );
+    assert_eq!(
+        next("0 0 * * FRI#3", "2022-12-01T00:00:00Z").unwrap(),
+        "2022-12-16T00:00:00Z"
+    );
+    assert_eq!(
+        next("0 0 * * 1#5", "2022-12-01T00:00:00Z").unwrap(),
+        "2023-01-30T00:00:00Z"
+    );
+}
+
+#[test]
+fn test_prev_before() {
+    assert_eq!(
+        prev("0 9 * * MON-FRI", "2022-12-19T08:00:00+08:00").unwrap(),
+        "2022-12-16T09:00:00+08:00"
+    );
+    // strictly before
+    assert_eq!(
+        prev("*/15 * * * *", "2022-12-16T10:15:00Z").unwrap(),
+        "2022-12-16T10:00:00Z"
+    );
+    assert_eq!(
+        prev("*/15 * * * *", "2022-12-16T10:15:00.5Z").unwrap(),
+        "2022-12-16T10:15:00Z"
+    );
+    assert_eq!(
+        prev("15,45 10 1 * *", "2022-12-01T10:30:00Z").unwrap(),
+        "2022-12-01T10:15:00Z"
+    );
+    assert_eq!(
+        prev("15,45 10 1 * *", "2022-12-01T10:00:00Z").unwrap(),
+        "2022-11-01T10:45:00Z"
+    );
+    assert_eq!(
+        prev("0 0 L * *", "2023-03-15T00:00:00Z").unwrap(),
+        "2023-02-28T00:00:00Z"
+    );
+    assert_eq!(prev("0 0 30 2 *", "2022-12-16T10:00:00Z"), None);
+}
+
+#[test]
+fn test_upcoming() {
+    let cron = Cron::from_str("0 0 12 * * MON,WED").unwrap();
+    let v: Vec<String> = cron
+        .upcoming(&DateTime::from_str("2022-12-16T00:00:00-05:00").unwrap())
+        .take(3)
+        .map(|v| v.to_string())
+        .collect();
+    assert_eq!(
+        v,
+        [
+            "2022-12-19T12:00:00-05:00",
+            "2022-12-21T12:00:00-05:00",
+            "2022-12-26T12:00:00-05:00",
+        ]
+    );
+    let cron = Cron::from_str("0 0 1 1 *").unwrap();
+    assert_eq!(cron.upcoming(&DateTime::MAX).next(), None);
+}
+
+#[test]
+fn test_serde() {
+    let v = Cron::from_str("0 9 * * MON-FRI").unwrap();
+    let js = serde_json::to_string(&v).unwrap();
+    assert_eq!(js, "\"0 9 * * MON-FRI\"");
+    assert_eq!(serde_json::from_str::<Cron>(&js).unwrap(), v);
+}
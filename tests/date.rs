@@ -85,9 +85,12 @@ fn test_set_year() {
     assert_eq!(d.get_year(), 2024);
 
     let d = Date::from_str("2024-02-01").unwrap().set_year(-1);
-    assert_eq!(d.to_string(), "2024-02-01");
+    assert_eq!(d.to_string(), "-000001-02-01");
 
     let d = Date::from_str("2024-02-01").unwrap().set_year(10000);
+    assert_eq!(d.to_string(), "+010000-02-01");
+
+    let d = Date::from_str("2024-02-01").unwrap().set_year(1_000_000);
     assert_eq!(d.to_string(), "2024-02-01");
 }
 
@@ -126,3 +129,180 @@ fn test_ser() {
     let v = serde_json::to_string(&d).unwrap();
     assert_eq!(v, "\"2022-12-13T11:12:13Z\"");
 }
+
+#[test]
+fn test_new() {
+    assert_eq!(Date::new(2024, 2, 29).unwrap().to_string(), "2024-02-29");
+    assert_eq!(
+        Date::new(2023, 2, 29).err().unwrap().to_string(),
+        "OutOfRangeDay"
+    );
+    assert_eq!(
+        Date::new(2023, 13, 1).err().unwrap().to_string(),
+        "OutOfRangeMonth"
+    );
+    assert_eq!(
+        Date::new(1_000_000, 1, 1).err().unwrap().to_string(),
+        "OutOfRangeYear"
+    );
+}
+
+#[test]
+fn test_is_valid() {
+    assert!(Date::new(2024, 4, 30).unwrap().is_valid());
+    assert!(Date::new(2024, 4, 31).is_err());
+}
+
+#[test]
+fn test_with() {
+    let d = Date::new(2024, 1, 31).unwrap();
+    assert!(d.clone().with_mon(2).is_err());
+    assert_eq!(d.clone().with_mon(3).unwrap().to_string(), "2024-03-31");
+    assert!(d.clone().with_day(32).is_err());
+    assert_eq!(d.clone().with_year(2000).unwrap().to_string(), "2000-01-31");
+    let d = Date::new(2024, 2, 29).unwrap();
+    assert!(d.clone().with_year(2023).is_err());
+}
+
+#[test]
+fn test_set_day_month_length() {
+    let d = Date::from_str("2024-02-01").unwrap().set_day(30);
+    assert_eq!(d.to_string(), "2024-02-01");
+    let d = Date::from_str("2024-01-31").unwrap().set_mon(2);
+    assert_eq!(d.to_string(), "2024-01-31");
+}
+
+#[test]
+fn test_expanded_year() {
+    let d = Date::from_str("+012345-01-01").unwrap();
+    assert_eq!(d.get_year(), 12345);
+    assert_eq!(d.to_string(), "+012345-01-01");
+    let d = Date::from_str("-0044-03-15").unwrap();
+    assert_eq!(d.get_year(), -44);
+    assert_eq!(d.to_string(), "-000044-03-15");
+    let d = Date::from_str("+275760-09-13").unwrap();
+    assert_eq!(d.to_string(), "+275760-09-13");
+    let d = Date::from_str("+2024-02-29").unwrap();
+    assert_eq!(d.to_string(), "2024-02-29");
+    assert!(Date::from_str("-0001-02-29").is_err());
+    assert!(Date::from_str("-0004-02-29").is_ok());
+    assert!(Date::from_str("+1234567-01-01").is_err());
+    assert!(Date::from_str("+123-01-01").is_err());
+    assert!(Date::from_str("+012345-01").is_err());
+    for v in ["-000044-03-15", "+012345-01-01", "-999999-01-01"] {
+        assert_eq!(Date::from_str(v).unwrap().to_string(), v);
+    }
+    let mut buf = [0u8; 13];
+    let len = Date::from_str("+012345-01-01")
+        .unwrap()
+        .display_date(0, &mut buf);
+    assert_eq!(&buf[..len], b"+012345-01-01");
+}
+
+#[test]
+fn test_min_max() {
+    assert_eq!(Date::MIN.to_string(), "-999999-01-01");
+    assert_eq!(Date::MAX.to_string(), "+999999-12-31");
+    assert!(Date::MIN.is_valid());
+    assert!(Date::MAX.is_valid());
+    assert_eq!(Date::from_str(&Date::MIN.to_string()).unwrap(), Date::MIN);
+    assert_eq!(Date::from_str(&Date::MAX.to_string()).unwrap(), Date::MAX);
+}
+
+#[test]
+fn test_julian_day() {
+    let d = Date::new(2022, 12, 13).unwrap();
+    assert_eq!(d.to_julian_day(), 2459927);
+    assert_eq!(Date::from_julian_day(2459927).unwrap(), d);
+    assert_eq!(Date::new(1970, 1, 1).unwrap().to_julian_day(), 2440588);
+    assert_eq!(Date::new(2000, 1, 1).unwrap().to_julian_day(), 2451545);
+    assert_eq!(
+        Date::from_julian_day(0).unwrap().to_string(),
+        "-004713-11-24"
+    );
+    assert!(Date::from_julian_day(i64::MAX / 2).is_err());
+}
+
+#[test]
+fn test_mjd() {
+    let d = Date::new(2022, 12, 13).unwrap();
+    assert_eq!(d.to_mjd(), 59926);
+    assert_eq!(Date::from_mjd(59926).unwrap(), d);
+    assert_eq!(Date::from_mjd(0).unwrap().to_string(), "1858-11-17");
+    assert!(Date::from_mjd(i64::MIN / 2).is_err());
+}
+
+#[test]
+fn test_rata_die() {
+    assert_eq!(Date::new(1, 1, 1).unwrap().to_rata_die(), 1);
+    assert_eq!(Date::new(1970, 1, 1).unwrap().to_rata_die(), 719163);
+    assert_eq!(Date::from_rata_die(0).unwrap().to_string(), "0000-12-31");
+    for rd in [-1_000_000, -1, 1, 738502, 3_000_000] {
+        assert_eq!(Date::from_rata_die(rd).unwrap().to_rata_die(), rd);
+    }
+}
+
+#[test]
+fn test_start_end_of() {
+    use fastdate::{CalendarUnit, WeekStart};
+    let d = Date::new(2022, 12, 13).unwrap();
+    for (unit, start, end, next) in [
+        (CalendarUnit::Day, "2022-12-13", "2022-12-13", "2022-12-14"),
+        (
+            CalendarUnit::Week(WeekStart::Monday),
+            "2022-12-12",
+            "2022-12-18",
+            "2022-12-19",
+        ),
+        (
+            CalendarUnit::Week(WeekStart::Sunday),
+            "2022-12-11",
+            "2022-12-17",
+            "2022-12-18",
+        ),
+        (
+            CalendarUnit::Month,
+            "2022-12-01",
+            "2022-12-31",
+            "2023-01-01",
+        ),
+        (
+            CalendarUnit::Quarter,
+            "2022-10-01",
+            "2022-12-31",
+            "2023-01-01",
+        ),
+        (CalendarUnit::Year, "2022-01-01", "2022-12-31", "2023-01-01"),
+    ] {
+        assert_eq!(d.start_of(unit).to_string(), start, "{:?}", unit);
+        assert_eq!(d.end_of(unit).to_string(), end, "{:?}", unit);
+        assert_eq!(
+            d.end_of_exclusive(unit).unwrap().to_string(),
+            next,
+            "{:?}",
+            unit
+        );
+    }
+    let d = Date::new(2024, 2, 11).unwrap();
+    assert_eq!(d.end_of(CalendarUnit::Month).to_string(), "2024-02-29");
+    assert_eq!(d.end_of(CalendarUnit::Quarter).to_string(), "2024-03-31");
+    assert_eq!(
+        d.start_of(CalendarUnit::Week(WeekStart::Sunday))
+            .to_string(),
+        "2024-02-11"
+    );
+    assert_eq!(
+        d.start_of(CalendarUnit::Week(WeekStart::Monday))
+            .to_string(),
+        "2024-02-05"
+    );
+    assert_eq!(
+        Date::MIN.start_of(CalendarUnit::Week(WeekStart::Monday)),
+        Date::MIN
+    );
+    assert_eq!(
+        Date::MAX.end_of(CalendarUnit::Week(WeekStart::Monday)),
+        Date::MAX
+    );
+    assert!(Date::MAX.end_of_exclusive(CalendarUnit::Day).is_err());
+}
@@ -138,37 +138,21 @@ fn test_timestamp_nano() {
 
 #[test]
 fn test_date_time() {
-    let new_time = DateTime::from((
-        Date {
-            day: 12,
-            mon: 12,
-            year: 2023,
-        },
-        Time {
-            nano: 12,
-            sec: 12,
-            minute: 12,
-            hour: 12,
-        },
-    ));
+    let new_time = DateTime::try_from((
+        Date::new(2023, 12, 12).unwrap(),
+        Time::new(12, 12, 12, 12).unwrap(),
+    ))
+    .unwrap();
     assert_eq!(new_time.to_string(), "2023-12-12T12:12:12.000000012Z");
 }
 
 #[test]
 fn test_set_offset() {
-    let new_time = DateTime::from((
-        Date {
-            day: 12,
-            mon: 12,
-            year: 2023,
-        },
-        Time {
-            nano: 12,
-            sec: 12,
-            minute: 12,
-            hour: 12,
-        },
-    ));
+    let new_time = DateTime::try_from((
+        Date::new(2023, 12, 12).unwrap(),
+        Time::new(12, 12, 12, 12).unwrap(),
+    ))
+    .unwrap();
     let timestamp_nano = new_time.unix_timestamp_nano();
     assert_eq!(new_time.to_string(), "2023-12-12T12:12:12.000000012Z");
     let new_time = new_time.set_offset(8 * 3600);
@@ -178,20 +162,12 @@ fn test_set_offset() {
 
 #[test]
 fn test_set_offset2() {
-    let new_time = DateTime::from((
-        Date {
-            day: 12,
-            mon: 12,
-            year: 2023,
-        },
-        Time {
-            nano: 12,
-            sec: 12,
-            minute: 12,
-            hour: 12,
-        },
+    let new_time = DateTime::try_from((
+        Date::new(2023, 12, 12).unwrap(),
+        Time::new(12, 12, 12, 12).unwrap(),
         8 * 3600,
-    ));
+    ))
+    .unwrap();
     println!("{}", new_time.display_stand());
     assert_eq!(
         new_time.display(true),
@@ -225,11 +201,7 @@ fn test_offset_zone() {
 
 #[test]
 fn test_offset_zone2() {
-    let mut epoch = fastdate::DateTime::from(Date {
-        day: 1,
-        mon: 1,
-        year: 2000,
-    });
+    let mut epoch = fastdate::DateTime::from(Date::new(2000, 1, 1).unwrap());
     println!("{}", epoch);
     epoch = epoch.set_offset(8 * 3600).add_sub_sec(-8 * 3600);
     println!("{}", epoch);
@@ -366,7 +338,7 @@ fn test_time_sub_time() {
     let date = DateTime::from_str("2022-12-12 00:00:00").unwrap();
     let date2 = DateTime::from_str("2022-12-11 00:00:00").unwrap();
     let sub = date - date2;
-    assert_eq!(86400, sub.as_secs());
+    assert_eq!(86400, sub.whole_seconds());
 }
 
 #[test]
@@ -668,12 +640,14 @@ fn test_sub() {
 }
 
 #[test]
+#[allow(clippy::op_ref)]
 fn test_add_ref() {
     let date = DateTime::from_str("2013-10-06 00:00:00Z").unwrap() + &Duration::from_minute(1);
     assert_eq!(date.to_string(), "2013-10-06T00:01:00Z");
 }
 
 #[test]
+#[allow(clippy::op_ref)]
 fn test_sub_ref() {
     let date = DateTime::from_str("2013-10-06 00:00:00Z").unwrap() - &Duration::from_minute(1);
     assert_eq!(date.to_string(), "2013-10-05T23:59:00Z");
@@ -701,26 +675,18 @@ fn test_from_into() {
 
 #[test]
 fn test_from_date_offset() {
-    let dt = DateTime::from((
-        Date {
-            year: 0000,
-            day: 1,
-            mon: 1,
-        },
-        0,
-    ));
+    let dt = DateTime::from((Date::new(0000, 1, 1).unwrap(), 0));
     assert_eq!(dt.to_string(), "0000-01-01T00:00:00Z");
 }
 
 #[test]
 fn test_from_time_offset() {
-    let dt = DateTime::from(Time {
-        hour: 0,
-        minute: 0,
-        sec: 0,
-        nano: 0,
-    });
+    let dt = DateTime::try_from(Time::new(0, 0, 0, 0).unwrap()).unwrap();
     assert_eq!(dt.to_string(), "0000-01-01T00:00:00Z");
+    let dt = DateTime::try_from(Time::new(23, 59, 59, 1_500_000_000).unwrap()).unwrap();
+    assert!(dt.is_leap_second());
+    assert_eq!(dt.to_string(), "0000-01-01T23:59:60.5Z");
+    assert!(DateTime::try_from(Time::new(14, 30, 59, 1_500_000_000).unwrap()).is_err());
 }
 
 #[test]
@@ -761,11 +727,7 @@ fn test_from_unix() {
 
 #[test]
 fn test_add_sub() {
-    let epoch = fastdate::DateTime::from(fastdate::Date {
-        day: 1,
-        mon: 1,
-        year: 2000,
-    });
+    let epoch = fastdate::DateTime::from(fastdate::Date::new(2000, 1, 1).unwrap());
     let us: u64 = 693484748000000;
     let v = epoch + Duration::from_micros(us);
     println!("{}", v); //2023-02-14 07:37:40
@@ -774,114 +736,77 @@ fn test_add_sub() {
 
 #[test]
 fn test_display_date() {
-    let epoch = fastdate::DateTime::from(Date {
-        day: 1,
-        mon: 1,
-        year: 2000,
-    });
+    let epoch = fastdate::DateTime::from(Date::new(2000, 1, 1).unwrap());
     let v = epoch.display(false);
     assert_eq!(v, "2000-01-01T00:00:00");
 }
 
 #[test]
 fn test_display_datetime() {
-    let epoch = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 1233,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let epoch = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 1233).unwrap(),
+    ))
+    .unwrap();
     let v = epoch.display(false);
     assert_eq!(v, "2000-01-01T01:01:11.000001233");
 }
 
 #[test]
 fn test_display_stand() {
-    let epoch = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 1233,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let epoch = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 1233).unwrap(),
+    ))
+    .unwrap();
     let v = epoch.display_stand();
     assert_eq!(v, "2000-01-01 01:01:11.000001233");
 }
 
 #[test]
 fn test_do_display() {
-    let epoch = fastdate::DateTime::from(Date {
-        day: 1,
-        mon: 1,
-        year: 2000,
-    })
-    .set_offset(1);
+    let epoch = fastdate::DateTime::from(Date::new(2000, 1, 1).unwrap()).set_offset(1);
     let v = epoch.display(true);
     assert_eq!(v, "2000-01-01T00:00:01+00:00:01");
 }
 
 #[test]
 fn test_do_display2() {
-    let epoch = fastdate::DateTime::from(Date {
-        day: 1,
-        mon: 1,
-        year: 2000,
-    })
-    .set_offset(-1);
+    let epoch = fastdate::DateTime::from(Date::new(2000, 1, 1).unwrap()).set_offset(-1);
     let v = epoch.display(true);
     assert_eq!(v, "1999-12-31T23:59:59-00:00:01");
 }
 
 #[test]
 fn test_set_micro() {
-    let mut dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 1233,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let mut dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 1233).unwrap(),
+    ))
+    .unwrap();
     dt = dt.set_nano(0);
     assert_eq!(dt.display_stand(), "2000-01-01 01:01:11");
 
     dt = dt.set_nano(1);
-    assert_eq!(dt.display_stand(), "2000-01-01 01:01:11.000001");
+    assert_eq!(dt.display_stand(), "2000-01-01 01:01:11.000000001");
+    dt = dt.set_nano(1_000_000_000);
+    assert_eq!(dt.nano(), 1);
+
+    let leap = DateTime::from_str("2016-12-31T23:59:60.5Z").unwrap();
+    let dt = leap.clone().set_nano(1_250_000_000);
+    assert!(dt.is_leap_second());
+    assert_eq!(dt.to_string(), "2016-12-31T23:59:60.25Z");
+    let dt = leap.set_nano(250_000_000);
+    assert_eq!(dt.to_string(), "2016-12-31T23:59:59.25Z");
 }
 
 #[test]
 fn test_format() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123456789,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123456789).unwrap(),
+    ))
+    .unwrap();
     let f = dt.format("YYYY-MM-DD/hh/mm/ss.000000");
     assert_eq!(f, "2000-01-01/01/01/11.123456");
     let f = dt.format("YYYY-MM-DD/hh/mm/ss.000000000");
@@ -890,19 +815,11 @@ fn test_format() {
 
 #[test]
 fn test_format2() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123456000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123456000).unwrap(),
     ))
+    .unwrap()
     .set_offset(8 * 60 * 60);
     println!("dt={}", dt);
     let f = dt.format("YYYY-MM-DD/hh/mm/ss.000000/+00:00");
@@ -911,19 +828,11 @@ fn test_format2() {
 
 #[test]
 fn test_format3() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123456000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123456000).unwrap(),
     ))
+    .unwrap()
     .set_offset(-8 * 60 * 60);
     println!("dt={}", dt);
     let f = dt.format("YYYY-MM-DD/hh/mm/ss.000000/+00:00");
@@ -932,219 +841,123 @@ fn test_format3() {
 
 #[test]
 fn test_offset_sec_max() {
-    let mut dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123456000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let mut dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123456000).unwrap(),
+    ))
+    .unwrap();
     dt = dt.set_offset(86399);
     assert_eq!(dt.offset(), 86399);
 }
 
 #[test]
 fn test_offset_sec_min() {
-    let mut dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123456000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let mut dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123456000).unwrap(),
+    ))
+    .unwrap();
     dt = dt.set_offset(-86399);
     assert_eq!(dt.offset(), -86399);
 }
 
 #[test]
 fn test_get_nano() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123456000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123456000).unwrap(),
+    ))
+    .unwrap();
     assert_eq!(dt.nano(), 123456000);
 }
 
 #[test]
 fn test_get_ms() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123000000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123000000).unwrap(),
+    ))
+    .unwrap();
     assert_eq!(dt.ms(), 123u16);
 }
 
 #[test]
 fn test_get_micro() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123000000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123000000).unwrap(),
+    ))
+    .unwrap();
     assert_eq!(dt.micro(), 123000);
 }
 
 #[test]
 fn test_get_sec() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123000000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123000000).unwrap(),
+    ))
+    .unwrap();
     assert_eq!(dt.sec(), 11);
 }
 
 #[test]
 fn test_get_minute() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123000000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123000000).unwrap(),
+    ))
+    .unwrap();
     assert_eq!(dt.minute(), 1);
 }
 
 #[test]
 fn test_get_hour() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123000000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123000000).unwrap(),
+    ))
+    .unwrap();
     assert_eq!(dt.hour(), 1);
 }
 
 #[test]
 fn test_get_day() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123000000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123000000).unwrap(),
+    ))
+    .unwrap();
     assert_eq!(dt.day(), 1);
 }
 
 #[test]
 fn test_get_mon() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123000000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123000000).unwrap(),
+    ))
+    .unwrap();
     assert_eq!(dt.mon(), 1);
 }
 
 #[test]
 fn test_get_year() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123000000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123000000).unwrap(),
+    ))
+    .unwrap();
     assert_eq!(dt.year(), 2000);
 }
 
 #[test]
 fn test_get_week() {
-    let dt = fastdate::DateTime::from((
-        Date {
-            day: 1,
-            mon: 1,
-            year: 2000,
-        },
-        Time {
-            nano: 123000000,
-            sec: 11,
-            minute: 1,
-            hour: 1,
-        },
-    ));
+    let dt = fastdate::DateTime::try_from((
+        Date::new(2000, 1, 1).unwrap(),
+        Time::new(1, 1, 11, 123000000).unwrap(),
+    ))
+    .unwrap();
     assert_eq!(dt.week_day(), 6u8);
 }
 
@@ -1152,3 +965,460 @@ fn test_get_week() {
 fn test_from_system_time() {
     let _: DateTime = DateTime::from_system_time(SystemTime::now(), 0);
 }
+
+#[test]
+fn test_new() {
+    let dt = DateTime::new(
+        Date::new(2022, 12, 13).unwrap(),
+        Time::new(11, 12, 14, 123).unwrap(),
+        -3 * 3600,
+    )
+    .unwrap();
+    assert_eq!(dt.to_string(), "2022-12-13T11:12:14.000000123-03:00");
+    let date = Date::new(2022, 1, 1).unwrap();
+    let time = Time::new(0, 0, 0, 0).unwrap();
+    assert!(DateTime::new(date.clone(), time.clone(), 86400).is_err());
+    assert!(DateTime::try_from((date.clone(), time.clone(), -86400)).is_err());
+    assert_eq!(
+        DateTime::try_from((date, time, 3600)).unwrap().to_string(),
+        "2022-01-01T00:00:00+01:00"
+    );
+}
+
+#[test]
+fn test_expanded_year() {
+    let dt = DateTime::from_str("+012345-01-01T00:00:00Z").unwrap();
+    assert_eq!(dt.year(), 12345);
+    assert_eq!(dt.to_string(), "+012345-01-01T00:00:00Z");
+    let dt = DateTime::from_str("-0044-03-15T12:30:00.5+01:00").unwrap();
+    assert_eq!(dt.year(), -44);
+    assert_eq!(dt.to_string(), "-000044-03-15T12:30:00.5+01:00");
+    assert_eq!(dt.display_stand(), "-000044-03-15 12:30:00.5");
+    assert_eq!(dt.format("YYYY-MM-DD"), "-000044-03-15");
+    let dt = DateTime::from_str("+275760-09-13").unwrap();
+    assert_eq!(dt.display(false), "+275760-09-13T00:00:00");
+    assert!(DateTime::from_str("-0001-02-29T00:00:00Z").is_err());
+    let dt = DateTime::from(Date::from_str("-0044-03-15").unwrap());
+    assert_eq!(dt.to_string(), "-000044-03-15T00:00:00Z");
+    let v = "-000044-03-15T12:30:00.123456789+01:00";
+    let dt = DateTime::from_str(v).unwrap();
+    assert_eq!(DateTime::from_str(&dt.to_string()).unwrap(), dt);
+    let mut buf = [0u8; 41];
+    let len = dt.do_display(&mut buf, true);
+    assert_eq!(&buf[..len], v.as_bytes());
+}
+
+#[test]
+fn test_parse_expanded_year() {
+    let format = "YYYY-MM-DD hh:mm:ss.000000000+00:00";
+    let dt = DateTime::from_str("-000044-03-15T12:30:00.123456789+01:00").unwrap();
+    let v = dt.format(format);
+    assert_eq!(v, "-000044-03-15 12:30:00.123456789+01:00");
+    assert_eq!(DateTime::parse(format, &v).unwrap(), dt);
+    let dt = DateTime::from_str("+012345-01-02T03:04:05Z").unwrap();
+    assert_eq!(
+        DateTime::parse("YYYY/MM/DD hh:mm:ssZ", &dt.format("YYYY/MM/DD hh:mm:ssZ")).unwrap(),
+        dt
+    );
+    assert_eq!(
+        DateTime::parse("YYYY-MM-DD hh:mm:ssZ", "2022-12-13 11:12:14Z").unwrap(),
+        DateTime::from_str("2022-12-13T11:12:14Z").unwrap()
+    );
+}
+
+#[test]
+fn test_min_max() {
+    assert_eq!(DateTime::MIN.to_string(), "-999999-01-01T00:00:00Z");
+    assert_eq!(
+        DateTime::MAX.to_string(),
+        "+999999-12-31T23:59:59.999999999Z"
+    );
+    assert_eq!(
+        DateTime::from_str(&DateTime::MIN.to_string()).unwrap(),
+        DateTime::MIN
+    );
+    assert_eq!(
+        DateTime::from_str(&DateTime::MAX.to_string()).unwrap(),
+        DateTime::MAX
+    );
+    assert_eq!(Date::from(DateTime::MAX), Date::MAX);
+}
+
+#[test]
+fn test_julian_day() {
+    let dt = DateTime::from_str("2022-12-13T00:00:00Z").unwrap();
+    assert_eq!(dt.to_julian_day(), 2459926.5);
+    assert_eq!(dt.to_julian_day_parts(), (2459926, 43_200_000_000_000));
+    assert_eq!(DateTime::from_julian_day(2459926.5).unwrap(), dt);
+    let dt = DateTime::from_str("2022-12-13T08:00:00+08:00").unwrap();
+    assert_eq!(dt.to_julian_day(), 2459926.5);
+    let dt = DateTime::from_str("2000-01-01T12:00:00Z").unwrap();
+    assert_eq!(dt.to_julian_day_parts(), (2451545, 0));
+    assert!(DateTime::from_julian_day(f64::NAN).is_err());
+    assert!(DateTime::from_julian_day(1e300).is_err());
+}
+
+#[test]
+fn test_julian_day_parts_round_trip() {
+    for s in [
+        "2022-12-13T11:12:14.123456789Z",
+        "1858-11-17T00:00:00.000000001Z",
+        "1969-12-31T23:59:59.999999999Z",
+        "-004713-11-24T11:59:59.999999999Z",
+    ] {
+        let dt = DateTime::from_str(s).unwrap();
+        let (day, nano) = dt.to_julian_day_parts();
+        let v = DateTime::from_julian_day_parts(day, nano).unwrap();
+        assert_eq!(v.unix_timestamp_nano(), dt.unix_timestamp_nano());
+        let (day, nano) = dt.to_mjd_parts();
+        let v = DateTime::from_mjd_parts(day, nano).unwrap();
+        assert_eq!(v.unix_timestamp_nano(), dt.unix_timestamp_nano());
+    }
+}
+
+#[test]
+fn test_mjd() {
+    let dt = DateTime::from_str("2022-12-13T06:00:00Z").unwrap();
+    assert_eq!(dt.to_mjd(), 59926.25);
+    assert_eq!(dt.to_mjd_parts(), (59926, 21_600_000_000_000));
+    assert_eq!(DateTime::from_mjd(59926.25).unwrap(), dt);
+    assert_eq!(
+        DateTime::from_mjd_parts(0, 0).unwrap().to_string(),
+        "1858-11-17T00:00:00Z"
+    );
+    // a fraction just below a whole day is carried into the day
+    assert_eq!(
+        DateTime::from_mjd(-1e-16).unwrap().to_string(),
+        "1858-11-17T00:00:00Z"
+    );
+    assert!(DateTime::from_mjd_parts(0, 86_400_000_000_000).is_err());
+    assert!(DateTime::from_julian_day_parts(0, u64::MAX).is_err());
+    assert!(DateTime::from_julian_day_parts(0, 86_399_999_999_999).is_ok());
+}
+
+#[test]
+fn test_leap_second() {
+    use fastdate::LeapSecond;
+    let dt = DateTime::from_str("2016-12-31T23:59:60.5Z").unwrap();
+    assert!(dt.is_leap_second());
+    assert_eq!(dt.to_string(), "2016-12-31T23:59:60.5Z");
+    assert_eq!(dt.sec(), 59);
+    assert_eq!(dt.nano(), 1_500_000_000);
+    assert_eq!(dt.format("hh:mm:ss.000000"), "23:59:60.500000");
+    assert_eq!(
+        dt.clone().set_offset(8 * 3600).to_string(),
+        "2017-01-01T07:59:60.5+08:00"
+    );
+    let before = DateTime::from_str("2016-12-31T23:59:59.9Z").unwrap();
+    let after = DateTime::from_str("2017-01-01T00:00:00Z").unwrap();
+    assert!(before < dt && dt < after);
+    assert_eq!(dt.unix_timestamp(), before.unix_timestamp());
+    assert_eq!(
+        dt.clone().add(Duration::from_secs(1)).to_string(),
+        "2017-01-01T00:00:00.5Z"
+    );
+    assert_eq!(
+        DateTime::from_str("2017-01-01T07:59:60+08:00").unwrap(),
+        DateTime::from_str("2016-12-31T23:59:60Z").unwrap()
+    );
+    assert!(DateTime::from_str("2016-12-31T12:59:60Z").is_err());
+    let inner = dt.offset_date_time();
+    assert_eq!((inner.second(), inner.nanosecond()), (59, 500_000_000));
+    assert_eq!(fastdate::time1::OffsetDateTime::from(dt.clone()), inner);
+    assert!(!DateTime::from(inner).is_leap_second());
+    // a leap second is local second 60 at any minute, depending on the offset
+    let dt = DateTime::from_str("2017-01-01T05:29:60+05:30").unwrap();
+    assert!(dt.is_leap_second());
+    assert_eq!(Time::from(dt).to_string(), "05:29:60");
+    let dt = DateTime::from_str_leap("2016-12-31T23:59:60.5Z", LeapSecond::Clamp).unwrap();
+    assert_eq!(dt.to_string(), "2016-12-31T23:59:59.999999999Z");
+    assert!(DateTime::from_str_leap("2016-12-31T23:59:60Z", LeapSecond::Reject).is_err());
+    let time = Time::from_str("23:59:60").unwrap();
+    let dt = DateTime::new(Date::new(2016, 12, 31).unwrap(), time.clone(), 0).unwrap();
+    assert_eq!(dt.to_string(), "2016-12-31T23:59:60Z");
+    assert_eq!(Time::from(dt), time);
+    assert!(DateTime::new(Date::new(2016, 12, 31).unwrap(), time, 3600).is_err());
+    let dt = DateTime::parse("YYYY-MM-DD hh:mm:ssZ", "2016-12-31 23:59:60Z").unwrap();
+    assert!(dt.is_leap_second());
+}
+
+#[test]
+fn test_truncate_round_ceil() {
+    use fastdate::{RoundMode, TimeUnit};
+    let dt = DateTime::from_str("2022-12-13T01:12:14.5+08:00").unwrap();
+    assert_eq!(
+        dt.truncate(TimeUnit::Day).to_string(),
+        "2022-12-13T00:00:00+08:00"
+    );
+    assert_eq!(
+        dt.ceil(TimeUnit::Day).to_string(),
+        "2022-12-14T00:00:00+08:00"
+    );
+    assert_eq!(
+        dt.truncate(TimeUnit::Hour).to_string(),
+        "2022-12-13T01:00:00+08:00"
+    );
+    assert_eq!(
+        dt.round(TimeUnit::Second, RoundMode::HalfUp).to_string(),
+        "2022-12-13T01:12:15+08:00"
+    );
+    assert_eq!(
+        dt.round(TimeUnit::Second, RoundMode::HalfEven).to_string(),
+        "2022-12-13T01:12:14+08:00"
+    );
+    let dt = DateTime::from_str("2022-12-13T01:12:14+05:30").unwrap();
+    assert_eq!(
+        dt.truncate(TimeUnit::Hour).to_string(),
+        "2022-12-13T01:00:00+05:30"
+    );
+    let unit = TimeUnit::Every {
+        step: Duration::from_secs(15 * 60),
+        origin: Duration::from_secs(5 * 60),
+    };
+    assert_eq!(dt.truncate(unit).to_string(), "2022-12-13T01:05:00+05:30");
+    let dt = DateTime::from_str("1969-12-31T23:59:59.5Z").unwrap();
+    assert_eq!(
+        dt.truncate(TimeUnit::Second).to_string(),
+        "1969-12-31T23:59:59Z"
+    );
+    assert_eq!(DateTime::MAX.ceil(TimeUnit::Day), DateTime::MAX);
+    assert_eq!(DateTime::MIN.truncate(TimeUnit::Hour), DateTime::MIN);
+    let dt = DateTime::from_str("2016-12-31T23:59:60.5Z").unwrap();
+    assert_eq!(
+        dt.truncate(TimeUnit::Second).to_string(),
+        "2016-12-31T23:59:59Z"
+    );
+}
+
+#[test]
+fn test_sub_datetime() {
+    use fastdate::SignedDuration;
+    let a = DateTime::from_str("2022-12-13T11:12:14Z").unwrap();
+    let b = DateTime::from_str("2022-12-13T11:12:15.5Z").unwrap();
+    assert_eq!(b.clone() - a.clone(), SignedDuration::milliseconds(1500));
+    assert_eq!(a.clone() - b.clone(), SignedDuration::milliseconds(-1500));
+    assert!((a.clone() - b.clone()).is_negative());
+    assert!(Duration::try_from(a.clone() - b.clone()).is_err());
+    assert_eq!(
+        Duration::try_from(b.clone() - a.clone()).unwrap(),
+        Duration::from_millis(1500)
+    );
+    assert_eq!(a.abs_diff(&b), Duration::from_millis(1500));
+    let max = DateTime::MAX - DateTime::MIN;
+    assert!(max.whole_days() > 700_000_000);
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn test_add_signed() {
+    use fastdate::SignedDuration;
+    let a = DateTime::from_str("2022-12-13T11:12:14Z").unwrap();
+    assert_eq!(
+        (a.clone() + SignedDuration::hours(-12)).to_string(),
+        "2022-12-12T23:12:14Z"
+    );
+    assert_eq!(
+        (a.clone() - SignedDuration::hours(-12)).to_string(),
+        "2022-12-13T23:12:14Z"
+    );
+    assert_eq!(
+        (a.clone() + &SignedDuration::seconds(1)).to_string(),
+        "2022-12-13T11:12:15Z"
+    );
+    let d = SignedDuration::minutes(-5);
+    assert_eq!((a.clone() + d) - a.clone(), d);
+    assert_eq!(a.clone().add_sub_sec(i64::MIN / 1_000_000_000).year(), 1730);
+}
+
+#[test]
+fn test_with_components() {
+    let dt = DateTime::from_str("2022-12-13T11:12:14.5+08:00").unwrap();
+    assert_eq!(
+        dt.clone().with_year(2023).unwrap().to_string(),
+        "2023-12-13T11:12:14.5+08:00"
+    );
+    assert_eq!(
+        dt.clone().with_month(2).unwrap().to_string(),
+        "2022-02-13T11:12:14.5+08:00"
+    );
+    assert_eq!(
+        dt.clone().with_day(31).unwrap().to_string(),
+        "2022-12-31T11:12:14.5+08:00"
+    );
+    assert_eq!(
+        dt.clone().with_hour(1).unwrap().to_string(),
+        "2022-12-13T01:12:14.5+08:00"
+    );
+    assert_eq!(
+        dt.clone().with_minute(0).unwrap().to_string(),
+        "2022-12-13T11:00:14.5+08:00"
+    );
+    assert_eq!(
+        dt.clone().with_second(59).unwrap().to_string(),
+        "2022-12-13T11:12:59.5+08:00"
+    );
+    assert_eq!(
+        dt.clone().with_nano(0).unwrap().to_string(),
+        "2022-12-13T11:12:14+08:00"
+    );
+    assert!(dt.clone().with_month(13).is_err());
+    assert!(dt.clone().with_day(32).is_err());
+    assert!(dt.clone().with_hour(24).is_err());
+    assert!(dt.clone().with_minute(60).is_err());
+    assert!(dt.clone().with_second(60).is_err());
+    let leap = DateTime::from_str("2016-12-31T23:59:60.5Z").unwrap();
+    let v = leap.clone().with_second(30).unwrap();
+    assert!(!v.is_leap_second());
+    assert_eq!(v.to_string(), "2016-12-31T23:59:30.5Z");
+    assert_eq!(
+        leap.with_second(59).unwrap().to_string(),
+        "2016-12-31T23:59:59.5Z"
+    );
+    assert!(dt.clone().with_nano(1_000_000_000).is_err());
+    assert!(dt.clone().with_month(2).unwrap().with_day(29).is_err());
+    assert_eq!(
+        dt.clone()
+            .with_date(Date::new(2000, 1, 1).unwrap())
+            .unwrap()
+            .to_string(),
+        "2000-01-01T11:12:14.5+08:00"
+    );
+    assert_eq!(
+        dt.with_time(Time::new(7, 8, 9, 0).unwrap())
+            .unwrap()
+            .to_string(),
+        "2022-12-13T07:08:09+08:00"
+    );
+    let dt = DateTime::from_str("2016-12-31T23:59:59Z").unwrap();
+    assert!(
+        dt.clone()
+            .with_nano(1_500_000_000)
+            .unwrap()
+            .is_leap_second()
+    );
+}
+
+#[test]
+fn test_start_end_of() {
+    use fastdate::{CalendarUnit, WeekStart};
+    let dt = DateTime::from_str("2022-12-31T20:12:14+08:00").unwrap();
+    assert_eq!(
+        dt.start_of(CalendarUnit::Day).to_string(),
+        "2022-12-31T00:00:00+08:00"
+    );
+    assert_eq!(
+        dt.end_of(CalendarUnit::Month).to_string(),
+        "2022-12-31T23:59:59.999999999+08:00"
+    );
+    assert_eq!(
+        dt.end_of_exclusive(CalendarUnit::Year).unwrap().to_string(),
+        "2023-01-01T00:00:00+08:00"
+    );
+    assert_eq!(
+        dt.start_of(CalendarUnit::Week(WeekStart::Monday))
+            .to_string(),
+        "2022-12-26T00:00:00+08:00"
+    );
+    // the UTC value is in 2022-12-31 12:12:14, so the local day is used
+    let utc = dt.clone().set_offset(0);
+    assert_eq!(
+        utc.start_of(CalendarUnit::Day).to_string(),
+        "2022-12-31T00:00:00Z"
+    );
+    let dt = DateTime::from_str("2023-01-01T01:00:00+08:00").unwrap();
+    assert_eq!(
+        dt.start_of(CalendarUnit::Year).to_string(),
+        "2023-01-01T00:00:00+08:00"
+    );
+    assert_eq!(
+        dt.clone()
+            .set_offset(0)
+            .start_of(CalendarUnit::Year)
+            .to_string(),
+        "2022-01-01T00:00:00Z"
+    );
+    assert_eq!(DateTime::MAX.end_of(CalendarUnit::Year), DateTime::MAX);
+    assert!(DateTime::MAX.end_of_exclusive(CalendarUnit::Day).is_err());
+    assert_eq!(
+        DateTime::MIN.start_of(CalendarUnit::Week(WeekStart::Sunday)),
+        DateTime::MIN
+    );
+}
+
+#[test]
+fn test_checked_saturating() {
+    use fastdate::SignedDuration;
+    let dt = DateTime::from_str("2022-12-13T11:12:14+08:00").unwrap();
+    assert_eq!(
+        dt.clone()
+            .checked_add(Duration::from_secs(3600))
+            .unwrap()
+            .to_string(),
+        "2022-12-13T12:12:14+08:00"
+    );
+    assert_eq!(
+        dt.clone()
+            .checked_sub(Duration::from_secs(3600))
+            .unwrap()
+            .to_string(),
+        "2022-12-13T10:12:14+08:00"
+    );
+    assert!(dt.clone().checked_add(Duration::MAX).is_none());
+    assert!(dt.clone().checked_sub(Duration::MAX).is_none());
+    assert!(dt.clone().checked_add_signed(SignedDuration::MIN).is_none());
+    assert!(DateTime::MAX.checked_add(Duration::from_nanos(1)).is_none());
+    assert_eq!(
+        dt.clone().checked_sub_signed(SignedDuration::hours(-1)),
+        dt.clone().checked_add(Duration::from_secs(3600))
+    );
+    let max = dt.clone().saturating_add(Duration::MAX);
+    assert_eq!(max, DateTime::MAX);
+    assert_eq!(max.offset(), 0);
+    assert_eq!(dt.clone().saturating_sub(Duration::MAX), DateTime::MIN);
+    assert_eq!(
+        dt.clone().saturating_add_signed(SignedDuration::MIN),
+        DateTime::MIN
+    );
+    assert_eq!(
+        dt.clone().saturating_sub_signed(SignedDuration::MIN),
+        DateTime::MAX
+    );
+    assert_eq!(
+        dt.clone()
+            .saturating_add(Duration::from_secs(1))
+            .to_string(),
+        "2022-12-13T11:12:15+08:00"
+    );
+}
+
+#[test]
+fn test_try_from_timestamp() {
+    assert_eq!(
+        DateTime::try_from_timestamp(1_670_929_934)
+            .unwrap()
+            .to_string(),
+        "2022-12-13T11:12:14Z"
+    );
+    assert_eq!(
+        DateTime::try_from_timestamp_millis(-1).unwrap().to_string(),
+        "1969-12-31T23:59:59.999Z"
+    );
+    assert_eq!(
+        DateTime::try_from_timestamp_micros(1).unwrap().to_string(),
+        "1970-01-01T00:00:00.000001Z"
+    );
+    assert!(DateTime::try_from_timestamp(i64::MAX).is_err());
+    assert!(DateTime::try_from_timestamp(i64::MIN).is_err());
+    assert!(DateTime::try_from_timestamp_nano(i128::MIN).is_err());
+    assert!(DateTime::try_from_timestamp_millis(253_402_300_800_000).is_ok());
+    assert!(DateTime::try_from_timestamp_millis(i64::MAX).is_err());
+    assert_eq!(DateTime::from_timestamp(i64::MAX), DateTime::MAX);
+    assert_eq!(DateTime::from_timestamp_nano(i128::MIN), DateTime::MIN);
+    // 2^64 nanoseconds must not wrap
+    let v = DateTime::from_timestamp_nano(1 << 64);
+    assert_eq!(v.unix_timestamp_nano(), 1 << 64);
+    assert_eq!(
+        DateTime::from(SystemTime::from(DateTime::from_timestamp(-1))),
+        DateTime::from_timestamp(-1)
+    );
+}
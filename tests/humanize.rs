@@ -0,0 +1,125 @@
+use fastdate::{
+    DateTime, HumanDuration, HumanStyle, HumanUnit, Locale, RoundMode, SignedDuration, Thresholds,
+};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[test]
+fn test_format() {
+    let v = HumanDuration::new();
+    assert_eq!(v.format(Duration::ZERO), "0 seconds");
+    assert_eq!(v.format(Duration::from_secs(1)), "1 second");
+    assert_eq!(v.format(Duration::from_secs(44)), "44 seconds");
+    assert_eq!(v.format(Duration::from_secs(45)), "1 minute");
+    assert_eq!(v.format(Duration::from_secs(150)), "3 minutes");
+    assert_eq!(v.format(Duration::from_secs(44 * 60)), "44 minutes");
+    assert_eq!(v.format(Duration::from_secs(50 * 60)), "1 hour");
+    assert_eq!(v.format(Duration::from_secs(21 * 3600)), "21 hours");
+    assert_eq!(v.format(Duration::from_secs(22 * 3600)), "1 day");
+    assert_eq!(v.format(Duration::from_secs(25 * 86400)), "25 days");
+    assert_eq!(v.format(Duration::from_secs(26 * 86400)), "1 month");
+    assert_eq!(v.format(Duration::from_secs(300 * 86400)), "10 months");
+    assert_eq!(v.format(Duration::from_secs(345 * 86400)), "1 year");
+    assert_eq!(v.format(Duration::from_secs(3 * 365 * 86400)), "3 years");
+}
+
+#[test]
+fn test_format_relative() {
+    let v = HumanDuration::new();
+    assert_eq!(v.format_relative(SignedDuration::ZERO), "just now");
+    assert_eq!(
+        v.format_relative(SignedDuration::milliseconds(-500)),
+        "just now"
+    );
+    assert_eq!(
+        v.format_relative(SignedDuration::minutes(-3)),
+        "3 minutes ago"
+    );
+    assert_eq!(v.format_relative(SignedDuration::days(2)), "in 2 days");
+    let v = v.set_thresholds(Thresholds {
+        now: 10,
+        ..Thresholds::default()
+    });
+    assert_eq!(v.format_relative(SignedDuration::seconds(-9)), "just now");
+    assert_eq!(
+        v.format_relative(SignedDuration::seconds(-10)),
+        "10 seconds ago"
+    );
+}
+
+#[test]
+fn test_rounding_thresholds() {
+    let v = HumanDuration::new();
+    assert_eq!(
+        v.unit_of(Duration::from_secs(89)),
+        Some((1, HumanUnit::Minute))
+    );
+    assert_eq!(
+        v.unit_of(Duration::from_secs(90)),
+        Some((2, HumanUnit::Minute))
+    );
+    let v = v.set_rounding(Some(RoundMode::HalfDown));
+    assert_eq!(
+        v.unit_of(Duration::from_secs(90)),
+        Some((1, HumanUnit::Minute))
+    );
+    let v = v.set_rounding(None);
+    assert_eq!(
+        v.unit_of(Duration::from_secs(119)),
+        Some((1, HumanUnit::Minute))
+    );
+    assert_eq!(v.unit_of(Duration::ZERO), None);
+    // floored counts are at least 1 once promoted to a larger unit
+    assert_eq!(v.format(Duration::from_secs(50 * 60)), "1 hour");
+    assert_eq!(v.format(Duration::from_secs(27 * 86400)), "1 month");
+    assert_eq!(v.format(Duration::from_secs(23 * 3600)), "1 day");
+    assert_eq!(v.format(Duration::from_secs(340 * 86400)), "1 year");
+    assert_eq!(v.format(Duration::from_secs(150)), "2 minutes");
+    assert_eq!(v.format(Duration::from_secs(44)), "44 seconds");
+    assert_eq!(
+        v.format_relative(SignedDuration::minutes(-50)),
+        "1 hour ago"
+    );
+    let v = HumanDuration::new().set_thresholds(Thresholds {
+        day: 7,
+        week: 4,
+        ..Thresholds::default()
+    });
+    assert_eq!(v.format(Duration::from_secs(10 * 86400)), "1 week");
+    assert_eq!(v.format(Duration::from_secs(24 * 86400)), "3 weeks");
+    assert_eq!(v.format(Duration::from_secs(25 * 86400)), "1 month");
+}
+
+#[test]
+fn test_style_locale() {
+    let v = HumanDuration::new().set_style(HumanStyle::Short);
+    assert_eq!(v.format(Duration::from_secs(180)), "3m");
+    assert_eq!(v.format_relative(SignedDuration::days(-2)), "2d ago");
+    assert_eq!(v.format_relative(SignedDuration::days(60)), "in 2mo");
+    let v = HumanDuration::new().set_locale(Locale::ZH_CN);
+    assert_eq!(v.format_relative(SignedDuration::minutes(-3)), "3分钟前");
+    assert_eq!(v.format_relative(SignedDuration::days(2)), "2天后");
+    assert_eq!(v.format_relative(SignedDuration::ZERO), "刚刚");
+    let v = v.set_style(HumanStyle::Short);
+    assert_eq!(v.format(Duration::from_secs(3 * 3600)), "3时");
+    let fr = Locale {
+        now: "à l'instant",
+        past: "il y a {}",
+        future: "dans {}",
+        ..Locale::EN
+    };
+    let v = HumanDuration::new().set_locale(fr);
+    assert_eq!(
+        v.format_relative(SignedDuration::hours(-1)),
+        "il y a 1 hour"
+    );
+}
+
+#[test]
+fn test_humanize_relative_to() {
+    let now = DateTime::from_str("2022-12-13T11:12:14+08:00").unwrap();
+    let v = DateTime::from_str("2022-12-13T03:09:14Z").unwrap();
+    assert_eq!(v.humanize_relative_to(&now), "3 minutes ago");
+    assert_eq!(now.humanize_relative_to(&v), "in 3 minutes");
+    assert_eq!(now.humanize_relative_to(&now), "just now");
+}
@@ -0,0 +1,208 @@
+use fastdate::{CalendarUnit, Date, DateTime, Interval, IntervalSet};
+use std::str::FromStr;
+use std::time::Duration;
+
+fn dt(s: &str) -> DateTime {
+    DateTime::from_str(s).unwrap()
+}
+
+fn iv(s: &str) -> Interval<DateTime> {
+    Interval::from_str(s).unwrap()
+}
+
+fn date_iv(s: &str) -> Interval<Date> {
+    Interval::from_str(s).unwrap()
+}
+
+#[test]
+fn test_new() {
+    assert!(Interval::new(dt("2022-12-13T10:00:00Z"), dt("2022-12-13T09:00:00Z")).is_err());
+    let v = Interval::new(dt("2022-12-13T09:00:00Z"), dt("2022-12-13T09:00:00Z")).unwrap();
+    assert!(v.is_empty());
+    assert!(!v.contains(&dt("2022-12-13T09:00:00Z")));
+}
+
+#[test]
+fn test_contains_overlaps() {
+    let v = iv("2022-12-13T09:00:00Z/2022-12-13T10:00:00Z");
+    assert!(v.contains(&dt("2022-12-13T09:00:00Z")));
+    assert!(v.contains(&dt("2022-12-13T17:59:59+08:00")));
+    assert!(!v.contains(&dt("2022-12-13T10:00:00Z")));
+    assert!(v.overlaps(&iv("2022-12-13T09:59:00Z/PT1H")));
+    assert!(!v.overlaps(&iv("2022-12-13T10:00:00Z/PT1H")));
+    assert!(!v.overlaps(&iv("PT1H/2022-12-13T09:00:00Z")));
+}
+
+#[test]
+fn test_intersection_union_gap() {
+    let a = iv("2022-12-13T09:00:00Z/2022-12-13T11:00:00Z");
+    let b = iv("2022-12-13T10:00:00Z/2022-12-13T12:00:00Z");
+    let c = iv("2022-12-13T11:00:00Z/2022-12-13T12:00:00Z");
+    let d = iv("2022-12-13T13:00:00Z/2022-12-13T14:00:00Z");
+    assert_eq!(
+        a.intersection(&b),
+        Some(iv("2022-12-13T10:00:00Z/2022-12-13T11:00:00Z"))
+    );
+    assert_eq!(a.intersection(&c), None);
+    assert_eq!(
+        a.union(&c),
+        Some(iv("2022-12-13T09:00:00Z/2022-12-13T12:00:00Z"))
+    );
+    assert_eq!(a.union(&d), None);
+    assert_eq!(
+        a.gap(&d),
+        Some(iv("2022-12-13T11:00:00Z/2022-12-13T13:00:00Z"))
+    );
+    assert_eq!(d.gap(&a), a.gap(&d));
+    assert_eq!(a.gap(&c), None);
+    assert_eq!(a.gap(&b), None);
+}
+
+#[test]
+fn test_duration() {
+    assert_eq!(
+        iv("2022-12-13T09:00:00Z/2022-12-13T10:30:00.5Z").duration(),
+        Duration::from_millis(5_400_500)
+    );
+    let v = date_iv("2022-12-01/2023-01-01");
+    assert_eq!(v.days(), 31);
+    assert_eq!(v.duration(), Duration::from_secs(31 * 86400));
+}
+
+#[test]
+fn test_split_by() {
+    let v = iv("2022-12-13T22:00:00+08:00/2022-12-15T06:00:00+08:00");
+    let days = v.split_by(CalendarUnit::Day);
+    assert_eq!(
+        days.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+        vec![
+            "2022-12-13T22:00:00+08:00/2022-12-14T00:00:00+08:00",
+            "2022-12-14T00:00:00+08:00/2022-12-15T00:00:00+08:00",
+            "2022-12-15T00:00:00+08:00/2022-12-15T06:00:00+08:00",
+        ]
+    );
+    assert!(
+        iv("2022-12-13T22:00:00Z/PT0S")
+            .split_by(CalendarUnit::Day)
+            .is_empty()
+    );
+    let v = date_iv("2022-11-15/2023-02-10");
+    assert_eq!(
+        v.split_by(CalendarUnit::Month)
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "2022-11-15/2022-12-01",
+            "2022-12-01/2023-01-01",
+            "2023-01-01/2023-02-01",
+            "2023-02-01/2023-02-10",
+        ]
+    );
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!(
+        iv("2022-12-13T09:00:00Z/P1DT2H30M15.5S").to_string(),
+        "2022-12-13T09:00:00Z/2022-12-14T11:30:15.5Z"
+    );
+    assert_eq!(
+        iv("P1M/2022-03-31T00:00:00+08:00").to_string(),
+        "2022-02-28T00:00:00+08:00/2022-03-31T00:00:00+08:00"
+    );
+    assert_eq!(
+        iv("2022-01-31T12:00:00Z/P1Y1M").to_string(),
+        "2022-01-31T12:00:00Z/2023-02-28T12:00:00Z"
+    );
+    assert_eq!(
+        iv("2022-12-13T09:00:00Z/P2W").end,
+        dt("2022-12-27T09:00:00Z")
+    );
+    assert_eq!(
+        date_iv("2022-12-01/P1M").to_string(),
+        "2022-12-01/2023-01-01"
+    );
+    assert_eq!(
+        date_iv("P1W/2022-12-08").to_string(),
+        "2022-12-01/2022-12-08"
+    );
+    for s in [
+        "2022-12-13T09:00:00Z",
+        "2022-12-13T09:00:00Z/P",
+        "2022-12-13T09:00:00Z/PT",
+        "2022-12-13T09:00:00Z/P1DT",
+        "2022-12-13T09:00:00Z/P1H",
+        "2022-12-13T09:00:00Z/PT1D",
+        "2022-12-13T09:00:00Z/P1D1Y",
+        "2022-12-13T09:00:00Z/P1D1D",
+        "2022-12-13T09:00:00Z/PT1.5H",
+        "2022-12-13T09:00:00Z/PT0.1234567891S",
+        "2022-12-13T09:00:00Z/P999999999999999999Y",
+        "P1D/P1D",
+        "2022-12-13T10:00:00Z/2022-12-13T09:00:00Z",
+    ] {
+        assert!(Interval::<DateTime>::from_str(s).is_err(), "{}", s);
+    }
+    assert!(Interval::<Date>::from_str("2022-12-01/PT1H").is_err());
+}
+
+#[test]
+fn test_ser_de() {
+    let v = iv("2022-12-13T09:00:00Z/PT1H");
+    let js = serde_json::to_string(&v).unwrap();
+    assert_eq!(js, "\"2022-12-13T09:00:00Z/2022-12-13T10:00:00Z\"");
+    assert_eq!(serde_json::from_str::<Interval<DateTime>>(&js).unwrap(), v);
+    let v = date_iv("2022-12-01/P1M");
+    let js = serde_json::to_string(&v).unwrap();
+    assert_eq!(serde_json::from_str::<Interval<Date>>(&js).unwrap(), v);
+}
+
+#[test]
+fn test_interval_set() {
+    let mut set = IntervalSet::new();
+    set.insert(iv("2022-12-13T10:00:00Z/PT1H"));
+    set.insert(iv("2022-12-13T08:00:00Z/PT1H"));
+    set.insert(iv("2022-12-13T10:30:00Z/PT1H"));
+    set.insert(iv("2022-12-13T09:00:00Z/PT30M"));
+    set.insert(iv("2022-12-13T15:00:00Z/PT0S"));
+    assert_eq!(
+        set.intervals(),
+        &[
+            iv("2022-12-13T08:00:00Z/2022-12-13T09:30:00Z"),
+            iv("2022-12-13T10:00:00Z/2022-12-13T11:30:00Z"),
+        ]
+    );
+    assert_eq!(
+        set.gaps(),
+        vec![iv("2022-12-13T09:30:00Z/2022-12-13T10:00:00Z")]
+    );
+    assert!(set.contains(&dt("2022-12-13T11:00:00Z")));
+    assert!(!set.contains(&dt("2022-12-13T09:45:00Z")));
+
+    let free = IntervalSet::from(iv("2022-12-13T07:00:00Z/2022-12-13T12:00:00Z"));
+    assert_eq!(
+        free.difference(&set).intervals(),
+        &[
+            iv("2022-12-13T07:00:00Z/2022-12-13T08:00:00Z"),
+            iv("2022-12-13T09:30:00Z/2022-12-13T10:00:00Z"),
+            iv("2022-12-13T11:30:00Z/2022-12-13T12:00:00Z"),
+        ]
+    );
+    assert_eq!(free.intersection(&set), set);
+    assert_eq!(
+        free.union(&set).intervals(),
+        &[iv("2022-12-13T07:00:00Z/2022-12-13T12:00:00Z")]
+    );
+    let mut v = free.clone();
+    v.subtract(&iv("2022-12-13T06:00:00Z/2022-12-13T13:00:00Z"));
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_date_interval_set() {
+    let set: IntervalSet<Date> = [date_iv("2022-12-01/P10D"), date_iv("2022-12-11/P5D")]
+        .into_iter()
+        .collect();
+    assert_eq!(set.intervals(), &[date_iv("2022-12-01/2022-12-16")]);
+}
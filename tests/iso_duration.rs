@@ -0,0 +1,163 @@
+use fastdate::{Date, DateTime, IsoDuration, SignedDuration};
+use std::str::FromStr;
+use std::time::Duration;
+
+fn iso(s: &str) -> IsoDuration {
+    IsoDuration::from_str(s).unwrap()
+}
+
+fn datetime(s: &str) -> DateTime {
+    DateTime::from_str(s).unwrap()
+}
+
+#[test]
+fn test_from_str() {
+    let v = iso("P1Y2M3W10DT2H30M5.25S");
+    assert_eq!(
+        v,
+        IsoDuration {
+            negative: false,
+            years: 1,
+            months: 2,
+            weeks: 3,
+            days: 10,
+            hours: 2,
+            minutes: 30,
+            seconds: 5,
+            nanos: 250_000_000,
+        }
+    );
+    assert_eq!(v.to_string(), "P1Y2M3W10DT2H30M5.25S");
+    assert_eq!(iso("PT0.5S").to_string(), "PT0.5S");
+    assert_eq!(iso("-P1D").to_string(), "-P1D");
+    assert_eq!(iso("+P1D").to_string(), "P1D");
+    assert_eq!(iso("P0D").to_string(), "PT0S");
+    assert_eq!(iso("PT36H").to_string(), "PT36H");
+    assert_eq!(iso("PT1M0.000000001S").to_string(), "PT1M0.000000001S");
+    assert_eq!(IsoDuration::default().to_string(), "PT0S");
+    assert!(iso("P0D").is_zero());
+    for v in [
+        "",
+        "P",
+        "PT",
+        "1D",
+        "P1",
+        "PD",
+        "P1H",
+        "PT1D",
+        "P1D1Y",
+        "P1M1M",
+        "P1.5D",
+        "PT1.5H",
+        "PT1.0000000001S",
+        "P1DT",
+        "P-1D",
+        "P1Y2",
+    ] {
+        assert!(IsoDuration::from_str(v).is_err(), "{}", v);
+    }
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn test_add_to() {
+    let v = datetime("2023-01-31T09:00:00+08:00");
+    assert_eq!(
+        (v.clone() + iso("P1M")).to_string(),
+        "2023-02-28T09:00:00+08:00"
+    );
+    assert_eq!(
+        (v.clone() + iso("P1Y1M")).to_string(),
+        "2024-02-29T09:00:00+08:00"
+    );
+    assert_eq!(
+        (v.clone() + iso("P1M1D")).to_string(),
+        "2023-03-01T09:00:00+08:00"
+    );
+    assert_eq!(
+        (v.clone() + iso("P1W")).to_string(),
+        "2023-02-07T09:00:00+08:00"
+    );
+    assert_eq!(
+        (v.clone() + iso("PT36H")).to_string(),
+        "2023-02-01T21:00:00+08:00"
+    );
+    assert_eq!(
+        (v.clone() + &iso("PT0.5S")).to_string(),
+        "2023-01-31T09:00:00.5+08:00"
+    );
+    assert_eq!(
+        (v.clone() - iso("P1M")).to_string(),
+        "2022-12-31T09:00:00+08:00"
+    );
+    assert_eq!(
+        (v.clone() + iso("-P1M")).to_string(),
+        "2022-12-31T09:00:00+08:00"
+    );
+    assert_eq!(
+        (v.clone() - &iso("PT1H")).to_string(),
+        "2023-01-31T08:00:00+08:00"
+    );
+    assert!(iso("P999999999Y").add_to(&v).is_err());
+    assert!(iso("PT999999999999999999H").add_to(&v).is_err());
+
+    let date = Date::from_str("2024-02-29").unwrap();
+    assert_eq!(
+        iso("P1Y").add_to_date(&date).unwrap().to_string(),
+        "2025-02-28"
+    );
+    assert_eq!(
+        iso("P1D").sub_from_date(&date).unwrap().to_string(),
+        "2024-02-28"
+    );
+    assert!(iso("PT1H").add_to_date(&date).is_err());
+}
+
+#[test]
+fn test_std_duration() {
+    assert_eq!(
+        Duration::try_from(iso("PT1H30M0.5S")).unwrap(),
+        Duration::from_millis(5_400_500)
+    );
+    assert!(Duration::try_from(iso("P1D")).is_err());
+    assert!(Duration::try_from(iso("-PT1S")).is_err());
+    assert_eq!(Duration::try_from(iso("-PT0S")).unwrap(), Duration::ZERO);
+    assert_eq!(
+        SignedDuration::try_from(iso("-PT1M")).unwrap(),
+        SignedDuration::minutes(-1)
+    );
+    assert!(SignedDuration::try_from(iso("P1M")).is_err());
+    assert_eq!(
+        IsoDuration::from(Duration::from_millis(5_400_500)).to_string(),
+        "PT1H30M0.5S"
+    );
+    assert_eq!(IsoDuration::from(Duration::ZERO).to_string(), "PT0S");
+
+    // nanos of a second or more carry into the seconds
+    let v = IsoDuration {
+        seconds: 1,
+        nanos: 1_500_000_000,
+        ..Default::default()
+    };
+    assert_eq!(Duration::try_from(v).unwrap(), Duration::from_millis(2500));
+    assert_eq!(
+        SignedDuration::try_from(v.negate()).unwrap(),
+        SignedDuration::milliseconds(-2500)
+    );
+    assert_eq!(v.to_string(), "PT2.5S");
+    assert_eq!(
+        v.add_to(&datetime("2023-01-31T09:00:00Z"))
+            .unwrap()
+            .to_string(),
+        "2023-01-31T09:00:02.5Z"
+    );
+}
+
+#[test]
+fn test_serde() {
+    let v = iso("P1Y2M10DT2H30M");
+    let js = serde_json::to_string(&v).unwrap();
+    assert_eq!(js, "\"P1Y2M10DT2H30M\"");
+    assert_eq!(serde_json::from_str::<IsoDuration>(&js).unwrap(), v);
+    assert!(serde_json::from_str::<IsoDuration>("\"1D\"").is_err());
+}
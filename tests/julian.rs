@@ -0,0 +1,120 @@
+use fastdate::{Cutover, Date, HistoricalDate, JulianDate};
+use std::str::FromStr;
+
+#[test]
+fn test_new() {
+    assert!(JulianDate::new(1500, 2, 29).is_ok());
+    assert!(JulianDate::new(1501, 2, 29).is_err());
+    assert!(JulianDate::new(-45, 2, 29).is_err());
+    assert!(JulianDate::new(-44, 2, 29).is_ok());
+    assert!(JulianDate::new(2022, 13, 1).is_err());
+    assert!(JulianDate::new(2000, 4, 31).is_err());
+    let j = JulianDate::new(-44, 2, 29).unwrap();
+    assert!(j.is_valid());
+    assert_eq!((j.get_year(), j.get_mon(), j.get_day()), (-44, 2, 29));
+}
+
+#[test]
+fn test_to_gregorian() {
+    let j = JulianDate::new(1582, 10, 4).unwrap();
+    let d = Date::try_from(j.clone()).unwrap();
+    assert_eq!(d.to_string(), "1582-10-14");
+    assert_eq!(JulianDate::from(d), j);
+
+    let j = JulianDate::new(1900, 2, 29).unwrap();
+    assert_eq!(Date::try_from(j).unwrap().to_string(), "1900-03-13");
+
+    let j = JulianDate::new(200, 3, 1).unwrap();
+    assert_eq!(Date::try_from(j).unwrap().to_string(), "0200-03-01");
+
+    let j = JulianDate::new(-4712, 1, 1).unwrap();
+    assert_eq!(j.to_julian_day(), 0);
+    assert_eq!(JulianDate::from_julian_day(0).unwrap(), j);
+}
+
+#[test]
+fn test_round_trip() {
+    for jdn in (-300_000_000..300_000_000).step_by(9_999_991) {
+        let j = JulianDate::from_julian_day(jdn).unwrap();
+        assert!(j.is_valid());
+        assert_eq!(j.to_julian_day(), jdn);
+    }
+    assert_eq!(
+        JulianDate::from(Date::MIN).to_julian_day(),
+        Date::MIN.to_julian_day()
+    );
+    assert_eq!(
+        JulianDate::from(Date::MAX).to_julian_day(),
+        Date::MAX.to_julian_day()
+    );
+}
+
+#[test]
+fn test_week_day() {
+    // 1582-10-04 (julian) was a Thursday, followed by Friday 1582-10-15
+    assert_eq!(JulianDate::new(1582, 10, 4).unwrap().week_day(), 4);
+    assert_eq!(Date::new(1582, 10, 15).unwrap().week_day(), 5);
+}
+
+#[test]
+fn test_from_str_display() {
+    let j = JulianDate::from_str("1700-02-29").unwrap();
+    assert_eq!(j.to_string(), "1700-02-29");
+    let j = JulianDate::from_str("-0044-03-15").unwrap();
+    assert_eq!(j.to_string(), "-000044-03-15");
+    assert!(JulianDate::from_str("1701-02-29").is_err());
+}
+
+#[test]
+fn test_ser_de() {
+    let j = JulianDate::new(1066, 10, 14).unwrap();
+    let js = serde_json::to_string(&j).unwrap();
+    assert_eq!(js, "\"1066-10-14\"");
+    let r: JulianDate = serde_json::from_str(&js).unwrap();
+    assert_eq!(r, j);
+}
+
+#[test]
+fn test_cutover_rome() {
+    let cutover = Cutover::default();
+    assert_eq!(cutover, Cutover::ROME);
+    let d = cutover.parse("1582-10-04").unwrap();
+    assert_eq!(d.to_string(), "1582-10-14");
+    assert_eq!(cutover.format(&d), "1582-10-04");
+    let next = cutover.parse("1582-10-15").unwrap();
+    assert_eq!(next.to_julian_day(), d.to_julian_day() + 1);
+    assert_eq!(cutover.format(&next), "1582-10-15");
+    assert!(cutover.parse("1582-10-10").is_err());
+    assert!(cutover.parse("1582-10-05").is_err());
+    assert!(cutover.to_date(1500, 2, 29).is_ok());
+    assert!(cutover.to_date(1700, 2, 29).is_err());
+}
+
+#[test]
+fn test_cutover_britain() {
+    let cutover = Cutover::BRITAIN;
+    let d = cutover.parse("1752-09-02").unwrap();
+    assert_eq!(cutover.format(&d), "1752-09-02");
+    assert!(cutover.parse("1752-09-03").is_err());
+    assert!(cutover.parse("1752-09-13").is_err());
+    let next = cutover.parse("1752-09-14").unwrap();
+    assert_eq!(next.to_julian_day(), d.to_julian_day() + 1);
+    assert_eq!(cutover.historical(&d).week_day(), 3);
+    assert_eq!(cutover.historical(&next).week_day(), 4);
+    assert_eq!(
+        cutover.historical(&Date::new(1700, 3, 11).unwrap()),
+        HistoricalDate::Julian(JulianDate::new(1700, 2, 29).unwrap())
+    );
+    let custom = Cutover::new(Date::new(1918, 2, 14).unwrap());
+    assert_eq!(
+        custom.format(&Date::new(1918, 2, 13).unwrap()),
+        "1918-01-31"
+    );
+}
+
+#[test]
+fn test_from_julian_day_out_of_range() {
+    assert!(JulianDate::from_julian_day(i64::MAX).is_err());
+    assert!(JulianDate::from_julian_day(i64::MIN).is_err());
+    assert!(JulianDate::from_julian_day(400_000_000).is_err());
+}
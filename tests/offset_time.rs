@@ -0,0 +1,78 @@
+use fastdate::{Date, DateTime, OffsetTime, Time};
+use std::str::FromStr;
+
+#[test]
+fn test_from_str() {
+    let t = OffsetTime::from_str("11:12:14+08:00").unwrap();
+    assert_eq!(t.get_time(), &Time::new(11, 12, 14, 0).unwrap());
+    assert_eq!(t.get_offset(), 8 * 3600);
+    assert_eq!(t.to_string(), "11:12:14+08:00");
+    for (s, v) in [
+        ("11:12:14.5Z", "11:12:14.5Z"),
+        ("11:12:14.123456-03:30", "11:12:14.123456-03:30"),
+        ("11:12+0800", "11:12:00+08:00"),
+        ("111214+08", "11:12:14+08:00"),
+        ("11:12:14+00:00", "11:12:14Z"),
+        ("11:12:14-00:00:30", "11:12:14-00:00:30"),
+    ] {
+        assert_eq!(OffsetTime::from_str(s).unwrap().to_string(), v, "{}", s);
+    }
+    for s in [
+        "11:12:14",
+        "11:12:14+8",
+        "11:12:14+08:60",
+        "11:12:14+24:00",
+        "11:12:14+08:00x",
+    ] {
+        assert!(OffsetTime::from_str(s).is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn test_to_utc() {
+    let t = OffsetTime::from_str("07:00:00+08:00").unwrap();
+    assert_eq!(t.to_utc(), (Time::new(23, 0, 0, 0).unwrap(), -1));
+    let t = OffsetTime::from_str("22:30:00-03:00").unwrap();
+    assert_eq!(t.to_utc(), (Time::new(1, 30, 0, 0).unwrap(), 1));
+    let t = OffsetTime::from_str("12:00:00Z").unwrap();
+    assert_eq!(t.to_utc(), (Time::new(12, 0, 0, 0).unwrap(), 0));
+    let (v, days) = t.to_offset(-5 * 3600);
+    assert_eq!((v.to_string().as_str(), days), ("07:00:00-05:00", 0));
+    let t = OffsetTime::from_str("07:59:60.5+08:00").unwrap();
+    assert_eq!(t.to_utc().0.to_string(), "23:59:60.5");
+}
+
+#[test]
+fn test_ord() {
+    let a = OffsetTime::from_str("11:00:00+08:00").unwrap();
+    let b = OffsetTime::from_str("04:00:00Z").unwrap();
+    let c = OffsetTime::from_str("03:00:00Z").unwrap();
+    assert!(a < b);
+    assert!(a > OffsetTime::from_str("02:59:59Z").unwrap());
+    assert!(c < a);
+    assert_ne!(a, OffsetTime::from_str("03:00:00Z").unwrap());
+}
+
+#[test]
+fn test_with_date() {
+    let t = OffsetTime::from_str("11:12:14.5+08:00").unwrap();
+    let dt = t.with_date(Date::new(2022, 12, 13).unwrap()).unwrap();
+    assert_eq!(dt.to_string(), "2022-12-13T11:12:14.5+08:00");
+    assert_eq!(OffsetTime::from(dt), t);
+    let dt = DateTime::from_str("2022-12-13T11:12:14Z").unwrap();
+    assert_eq!(OffsetTime::from(dt).to_string(), "11:12:14Z");
+}
+
+#[test]
+fn test_new() {
+    assert!(OffsetTime::new(Time::new(11, 0, 0, 0).unwrap(), 86400).is_err());
+    assert!(OffsetTime::new(Time::new(11, 0, 0, 0).unwrap(), -86400).is_err());
+}
+
+#[test]
+fn test_ser_de() {
+    let t = OffsetTime::from_str("11:12:14+08:00").unwrap();
+    let js = serde_json::to_string(&t).unwrap();
+    assert_eq!(js, "\"11:12:14+08:00\"");
+    assert_eq!(serde_json::from_str::<OffsetTime>(&js).unwrap(), t);
+}
@@ -0,0 +1,141 @@
+use fastdate::{Date, Feb29Policy, MonthDay, YearMonth};
+use std::str::FromStr;
+
+#[test]
+fn test_year_month_from_str() {
+    let v = YearMonth::from_str("2022-12").unwrap();
+    assert_eq!(v, YearMonth::new(2022, 12).unwrap());
+    assert_eq!(v.to_string(), "2022-12");
+    let v = YearMonth::from_str("-0044-03").unwrap();
+    assert_eq!((v.get_year(), v.get_mon()), (-44, 3));
+    assert_eq!(v.to_string(), "-000044-03");
+    assert!(YearMonth::from_str("2022-13").is_err());
+    assert!(YearMonth::from_str("2022-1").is_err());
+    assert!(YearMonth::from_str("2022/12").is_err());
+    assert!(YearMonth::from_str("2022-12-01").is_err());
+    assert!(YearMonth::from_str("").is_err());
+}
+
+#[test]
+fn test_year_month_days() {
+    let v = YearMonth::new(2024, 2).unwrap();
+    assert_eq!(v.days_in_month(), 29);
+    assert_eq!(YearMonth::new(2023, 2).unwrap().days_in_month(), 28);
+    let days: Vec<Date> = v.days().collect();
+    assert_eq!(days.len(), 29);
+    assert_eq!(days[0], v.first_day());
+    assert_eq!(days[28], v.last_day());
+    assert_eq!(v.last_day().to_string(), "2024-02-29");
+    assert_eq!(v.day(15).unwrap().to_string(), "2024-02-15");
+    assert!(v.day(30).is_err());
+}
+
+#[test]
+fn test_year_month_add_months() {
+    let v = YearMonth::new(2022, 12).unwrap();
+    assert_eq!(v.add_months(1).unwrap().to_string(), "2023-01");
+    assert_eq!(v.add_months(-12).unwrap().to_string(), "2021-12");
+    assert_eq!(v.add_months(-2023 * 12).unwrap().to_string(), "-000001-12");
+    assert_eq!(v.add_months(0).unwrap(), v);
+    assert!(v.add_months(i32::MAX).is_err());
+}
+
+#[test]
+fn test_year_month_from_date_cmp() {
+    let v = YearMonth::from(Date::new(2022, 12, 13).unwrap());
+    assert_eq!(v.to_string(), "2022-12");
+    assert!(v < YearMonth::new(2023, 1).unwrap());
+    assert!(v > YearMonth::new(2022, 11).unwrap());
+    assert!(YearMonth::new(2022, 13).is_err());
+}
+
+#[test]
+fn test_year_month_ser_de() {
+    let v = YearMonth::new(2022, 12).unwrap();
+    let js = serde_json::to_string(&v).unwrap();
+    assert_eq!(js, "\"2022-12\"");
+    let r: YearMonth = serde_json::from_str(&js).unwrap();
+    assert_eq!(r, v);
+}
+
+#[test]
+fn test_month_day_from_str() {
+    let v = MonthDay::from_str("--12-13").unwrap();
+    assert_eq!(v, MonthDay::new(12, 13).unwrap());
+    assert_eq!(v.to_string(), "--12-13");
+    assert!(MonthDay::from_str("--02-29").is_ok());
+    assert!(MonthDay::from_str("--02-30").is_err());
+    assert!(MonthDay::from_str("--13-01").is_err());
+    assert!(MonthDay::from_str("12-13").is_err());
+    assert!(MonthDay::from_str("--12/13").is_err());
+}
+
+#[test]
+fn test_month_day_to_date() {
+    let v = MonthDay::new(2, 29).unwrap();
+    assert_eq!(
+        v.to_date(2024, Feb29Policy::Skip).unwrap().to_string(),
+        "2024-02-29"
+    );
+    assert_eq!(v.to_date(2023, Feb29Policy::Skip), None);
+    assert_eq!(
+        v.to_date(2023, Feb29Policy::Feb28).unwrap().to_string(),
+        "2023-02-28"
+    );
+    assert_eq!(
+        v.to_date(2023, Feb29Policy::Mar1).unwrap().to_string(),
+        "2023-03-01"
+    );
+}
+
+#[test]
+fn test_month_day_next_occurrence() {
+    let v = MonthDay::new(12, 13).unwrap();
+    let d = Date::new(2022, 12, 13).unwrap();
+    assert_eq!(
+        v.next_occurrence(&d, Feb29Policy::Skip)
+            .unwrap()
+            .to_string(),
+        "2023-12-13"
+    );
+    let d = Date::new(2022, 12, 12).unwrap();
+    assert_eq!(
+        v.next_occurrence(&d, Feb29Policy::Skip)
+            .unwrap()
+            .to_string(),
+        "2022-12-13"
+    );
+    let v = MonthDay::new(2, 29).unwrap();
+    let d = Date::new(2096, 3, 1).unwrap();
+    assert_eq!(
+        v.next_occurrence(&d, Feb29Policy::Skip)
+            .unwrap()
+            .to_string(),
+        "2104-02-29"
+    );
+    assert_eq!(
+        v.next_occurrence(&d, Feb29Policy::Feb28)
+            .unwrap()
+            .to_string(),
+        "2097-02-28"
+    );
+    assert_eq!(v.next_occurrence(&Date::MAX, Feb29Policy::Skip), None);
+}
+
+#[test]
+fn test_month_day_from_date_cmp() {
+    let v = MonthDay::from(Date::new(2022, 12, 13).unwrap());
+    assert_eq!(v.to_string(), "--12-13");
+    assert!(v > MonthDay::new(2, 29).unwrap());
+    assert_eq!((v.get_mon(), v.get_day()), (12, 13));
+    assert!(MonthDay::new(4, 31).is_err());
+}
+
+#[test]
+fn test_month_day_ser_de() {
+    let v = MonthDay::new(2, 29).unwrap();
+    let js = serde_json::to_string(&v).unwrap();
+    assert_eq!(js, "\"--02-29\"");
+    let r: MonthDay = serde_json::from_str(&js).unwrap();
+    assert_eq!(r, v);
+}
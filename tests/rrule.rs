@@ -0,0 +1,387 @@
+use fastdate::{DateTime, Frequency, RRule, RRuleSet, WeekdayNum};
+use std::str::FromStr;
+
+fn expand(rule: &str, start: &str, n: usize) -> Vec<String> {
+    let rule = RRule::from_str(rule).unwrap();
+    rule.iter(&DateTime::from_str(start).unwrap())
+        .take(n)
+        .map(|v| v.to_string())
+        .collect()
+}
+
+#[test]
+fn test_from_str() {
+    let v = RRule::from_str("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,-1FR;WKST=SU").unwrap_err();
+    assert_eq!(v.to_string(), "InvalidByDayNumber");
+    let v = RRule::from_str("FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU").unwrap();
+    assert_eq!(v.freq, Frequency::Monthly);
+    assert_eq!(v.interval, 2);
+    assert_eq!(v.count, Some(10));
+    assert_eq!(
+        v.by_day,
+        [
+            WeekdayNum { n: 1, week_day: 7 },
+            WeekdayNum { n: -1, week_day: 7 }
+        ]
+    );
+    assert_eq!(
+        v.to_string(),
+        "FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU"
+    );
+    let v = RRule::from_str("FREQ=WEEKLY;UNTIL=19971224T000000Z;WKST=SU;BYDAY=TU,TH").unwrap();
+    assert_eq!(
+        v.to_string(),
+        "FREQ=WEEKLY;UNTIL=19971224T000000Z;BYDAY=TU,TH;WKST=SU"
+    );
+    assert_eq!(RRule::from_str(&v.to_string()).unwrap(), v);
+    assert!(RRule::from_str("INTERVAL=2").is_err());
+    assert!(RRule::from_str("FREQ=DAILY;INTERVAL=0").is_err());
+    assert!(RRule::from_str("FREQ=DAILY;BYMONTHDAY=0").is_err());
+    assert!(RRule::from_str("FREQ=DAILY;BYHOUR=24").is_err());
+    assert!(RRule::from_str("FREQ=DAILY;COUNT=2;UNTIL=19971224T000000Z").is_err());
+    assert!(RRule::from_str("FREQ=DAILY;FOO=1").is_err());
+}
+
+#[test]
+fn test_monthly() {
+    assert_eq!(
+        expand(
+            "FREQ=MONTHLY;BYDAY=-1FR;COUNT=10",
+            "1997-09-05T09:00:00Z",
+            20
+        ),
+        [
+            "1997-09-26T09:00:00Z",
+            "1997-10-31T09:00:00Z",
+            "1997-11-28T09:00:00Z",
+            "1997-12-26T09:00:00Z",
+            "1998-01-30T09:00:00Z",
+            "1998-02-27T09:00:00Z",
+            "1998-03-27T09:00:00Z",
+            "1998-04-24T09:00:00Z",
+            "1998-05-29T09:00:00Z",
+            "1998-06-26T09:00:00Z",
+        ]
+    );
+    // the last work day of the month
+    assert_eq!(
+        expand(
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+            "1997-09-29T09:00:00-04:00",
+            3
+        ),
+        [
+            "1997-09-30T09:00:00-04:00",
+            "1997-10-31T09:00:00-04:00",
+            "1997-11-28T09:00:00-04:00",
+        ]
+    );
+    // months without the 31st are skipped
+    assert_eq!(
+        expand("FREQ=MONTHLY", "2023-01-31T08:00:00Z", 3),
+        [
+            "2023-01-31T08:00:00Z",
+            "2023-03-31T08:00:00Z",
+            "2023-05-31T08:00:00Z",
+        ]
+    );
+    assert_eq!(
+        expand("FREQ=MONTHLY;BYMONTHDAY=-3", "1997-09-28T09:00:00Z", 2),
+        ["1997-09-28T09:00:00Z", "1997-10-29T09:00:00Z"]
+    );
+}
+
+#[test]
+fn test_weekly() {
+    assert_eq!(
+        expand(
+            "FREQ=WEEKLY;INTERVAL=2;UNTIL=19971224T000000Z;WKST=SU;BYDAY=TU,TH",
+            "1997-11-25T09:00:00Z",
+            20
+        ),
+        [
+            "1997-11-25T09:00:00Z",
+            "1997-11-27T09:00:00Z",
+            "1997-12-09T09:00:00Z",
+            "1997-12-11T09:00:00Z",
+            "1997-12-23T09:00:00Z",
+        ]
+    );
+    // WKST changes which week an occurrence falls in
+    assert_eq!(
+        expand(
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO",
+            "1997-08-05T09:00:00Z",
+            10
+        ),
+        [
+            "1997-08-05T09:00:00Z",
+            "1997-08-10T09:00:00Z",
+            "1997-08-19T09:00:00Z",
+            "1997-08-24T09:00:00Z",
+        ]
+    );
+    assert_eq!(
+        expand(
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU",
+            "1997-08-05T09:00:00Z",
+            10
+        ),
+        [
+            "1997-08-05T09:00:00Z",
+            "1997-08-17T09:00:00Z",
+            "1997-08-19T09:00:00Z",
+            "1997-08-31T09:00:00Z",
+        ]
+    );
+}
+
+#[test]
+fn test_yearly() {
+    assert_eq!(
+        expand(
+            "FREQ=YEARLY;BYMONTH=1;BYDAY=SU,MO",
+            "1998-01-01T09:00:00+08:00",
+            3
+        ),
+        [
+            "1998-01-04T09:00:00+08:00",
+            "1998-01-05T09:00:00+08:00",
+            "1998-01-11T09:00:00+08:00",
+        ]
+    );
+    assert_eq!(
+        expand("FREQ=YEARLY;BYDAY=20MO", "1997-05-19T09:00:00Z", 3),
+        [
+            "1997-05-19T09:00:00Z",
+            "1998-05-18T09:00:00Z",
+            "1999-05-17T09:00:00Z",
+        ]
+    );
+    assert_eq!(
+        expand(
+            "FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO",
+            "1997-05-12T09:00:00Z",
+            3
+        ),
+        [
+            "1997-05-12T09:00:00Z",
+            "1998-05-11T09:00:00Z",
+            "1999-05-17T09:00:00Z",
+        ]
+    );
+    assert_eq!(
+        expand(
+            "FREQ=YEARLY;INTERVAL=3;COUNT=4;BYYEARDAY=1,100,200",
+            "1997-01-01T09:00:00Z",
+            10
+        ),
+        [
+            "1997-01-01T09:00:00Z",
+            "1997-04-10T09:00:00Z",
+            "1997-07-19T09:00:00Z",
+            "2000-01-01T09:00:00Z",
+        ]
+    );
+    // every Friday the 13th
+    assert_eq!(
+        expand(
+            "FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13",
+            "1997-09-02T09:00:00Z",
+            3
+        ),
+        [
+            "1998-02-13T09:00:00Z",
+            "1998-03-13T09:00:00Z",
+            "1998-11-13T09:00:00Z",
+        ]
+    );
+    // leap days only
+    assert_eq!(
+        expand("FREQ=YEARLY", "2024-02-29T00:00:00Z", 2),
+        ["2024-02-29T00:00:00Z", "2028-02-29T00:00:00Z"]
+    );
+}
+
+#[test]
+fn test_sub_daily() {
+    assert_eq!(
+        expand(
+            "FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000Z",
+            "1997-09-02T09:00:00Z",
+            10
+        ),
+        [
+            "1997-09-02T09:00:00Z",
+            "1997-09-02T12:00:00Z",
+            "1997-09-02T15:00:00Z",
+        ]
+    );
+    assert_eq!(
+        expand(
+            "FREQ=MINUTELY;INTERVAL=90;COUNT=4",
+            "1997-09-02T22:00:00Z",
+            10
+        ),
+        [
+            "1997-09-02T22:00:00Z",
+            "1997-09-02T23:30:00Z",
+            "1997-09-03T01:00:00Z",
+            "1997-09-03T02:30:00Z",
+        ]
+    );
+    assert_eq!(
+        expand(
+            "FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10",
+            "1997-09-02T09:00:00Z",
+            7
+        ),
+        [
+            "1997-09-02T09:00:00Z",
+            "1997-09-02T09:20:00Z",
+            "1997-09-02T09:40:00Z",
+            "1997-09-02T10:00:00Z",
+            "1997-09-02T10:20:00Z",
+            "1997-09-02T10:40:00Z",
+            "1997-09-03T09:00:00Z",
+        ]
+    );
+    assert_eq!(
+        expand(
+            "FREQ=DAILY;BYHOUR=9,17;BYMINUTE=30",
+            "2022-12-12T12:00:00Z",
+            3
+        ),
+        [
+            "2022-12-12T17:30:00Z",
+            "2022-12-13T09:30:00Z",
+            "2022-12-13T17:30:00Z",
+        ]
+    );
+    assert_eq!(
+        expand(
+            "FREQ=SECONDLY;INTERVAL=30;COUNT=3",
+            "2022-12-12T23:59:15.5Z",
+            10
+        ),
+        [
+            "2022-12-12T23:59:15.5Z",
+            "2022-12-12T23:59:45.5Z",
+            "2022-12-13T00:00:15.5Z",
+        ]
+    );
+}
+
+#[test]
+fn test_never_occurs() {
+    assert_eq!(
+        expand(
+            "FREQ=MONTHLY;BYMONTHDAY=30;BYMONTH=2",
+            "2022-01-01T00:00:00Z",
+            1
+        ),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        expand(
+            "FREQ=HOURLY;INTERVAL=24;BYHOUR=1",
+            "2022-01-01T00:00:00Z",
+            1
+        ),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        expand(
+            "FREQ=DAILY;INTERVAL=100000;BYMONTH=2;BYMONTHDAY=30",
+            "2022-01-01T00:00:00Z",
+            1
+        ),
+        Vec::<String>::new()
+    );
+    // 40 years without an occurrence is not never
+    assert_eq!(
+        expand(
+            "FREQ=DAILY;BYMONTH=2;BYMONTHDAY=29;BYDAY=MO",
+            "2072-03-01T00:00:00Z",
+            1
+        ),
+        ["2112-02-29T00:00:00Z"]
+    );
+}
+
+#[test]
+fn test_rrule_set() {
+    let set = RRuleSet::from_str(
+        "DTSTART:19970902T090000Z
+RRULE:FREQ=DAILY;COUNT=5
+RRULE:FREQ=WEEKLY;COUNT=2;BYDAY=FR
+RDATE;VALUE=DATE-TIME:19970901T090000Z,19970904T090000Z
+EXDATE:19970903T090000Z",
+    )
+    .unwrap();
+    let v: Vec<String> = set.iter().map(|v| v.to_string()).collect();
+    // the Tuesday dtstart is the first of the 2 weekly occurrences
+    assert_eq!(
+        v,
+        [
+            "1997-09-01T09:00:00Z",
+            "1997-09-02T09:00:00Z",
+            "1997-09-04T09:00:00Z",
+            "1997-09-05T09:00:00Z",
+            "1997-09-06T09:00:00Z",
+        ]
+    );
+    assert_eq!(
+        set.to_string(),
+        "DTSTART:19970902T090000Z
+RRULE:FREQ=DAILY;COUNT=5
+RRULE:FREQ=WEEKLY;COUNT=2;BYDAY=FR
+RDATE:19970901T090000Z,19970904T090000Z
+EXDATE:19970903T090000Z"
+    );
+    assert_eq!(RRuleSet::from_str(&set.to_string()).unwrap(), set);
+
+    let v = RRuleSet::from_str("DTSTART;TZID=America/New_York:19970902T090000").unwrap_err();
+    assert!(v.to_string().starts_with("UnsupportedTimeZone"));
+    assert!(RRuleSet::from_str("RRULE:FREQ=DAILY").is_err());
+
+    let set = RRuleSet::new(DateTime::from_str("2022-12-12T09:00:00+08:00").unwrap())
+        .add_rrule(RRule::from_str("FREQ=DAILY;COUNT=2").unwrap())
+        .add_exdate(DateTime::from_str("2022-12-12T01:00:00Z").unwrap());
+    let v: Vec<String> = set.iter().map(|v| v.to_string()).collect();
+    assert_eq!(v, ["2022-12-13T09:00:00+08:00"]);
+}
+
+#[test]
+fn test_set_dtstart_count() {
+    // 2022-12-01 is not the last Friday, it is still the first of the 2 occurrences
+    let set = RRuleSet::from_str(
+        "DTSTART:20221201T090000Z
+RRULE:FREQ=MONTHLY;BYDAY=-1FR;COUNT=2",
+    )
+    .unwrap();
+    let v: Vec<String> = set.iter().map(|v| v.to_string()).collect();
+    assert_eq!(v, ["2022-12-01T09:00:00Z", "2022-12-30T09:00:00Z"]);
+    // a dtstart the rule produces is counted once
+    let set = RRuleSet::from_str(
+        "DTSTART:20221230T090000Z
+RRULE:FREQ=MONTHLY;BYDAY=-1FR;COUNT=2",
+    )
+    .unwrap();
+    let v: Vec<String> = set.iter().map(|v| v.to_string()).collect();
+    assert_eq!(v, ["2022-12-30T09:00:00Z", "2023-01-27T09:00:00Z"]);
+    let set = RRuleSet::from_str(
+        "DTSTART:20221201T090000Z
+RRULE:FREQ=MONTHLY;BYDAY=-1FR;COUNT=1",
+    )
+    .unwrap();
+    assert_eq!(set.iter().count(), 1);
+}
+
+#[test]
+fn test_serde() {
+    let v = RRule::from_str("FREQ=DAILY;COUNT=2").unwrap();
+    let js = serde_json::to_string(&v).unwrap();
+    assert_eq!(js, "\"FREQ=DAILY;COUNT=2\"");
+    assert_eq!(serde_json::from_str::<RRule>(&js).unwrap(), v);
+}
@@ -0,0 +1,276 @@
+use fastdate::serde::{Format, Lenient};
+use fastdate::serde::{
+    date_days, date_days_option, flexible, flexible_option, time_seconds, time_seconds_option,
+    ts_microseconds, ts_milliseconds, ts_nanoseconds, ts_nanoseconds_option, ts_seconds,
+    ts_seconds_option,
+};
+use fastdate::{Date, DateTime, Time};
+use serde_json::json;
+use serde_json::value::Serializer;
+use std::str::FromStr;
+
+fn datetime(s: &str) -> DateTime {
+    DateTime::from_str(s).unwrap()
+}
+
+#[test]
+fn test_ts() {
+    let v = datetime("2022-12-13T11:12:14.123456789+08:00");
+    assert_eq!(
+        ts_seconds::serialize(&v, Serializer).unwrap(),
+        json!(1670901134)
+    );
+    assert_eq!(
+        ts_milliseconds::serialize(&v, Serializer).unwrap(),
+        json!(1670901134123i64)
+    );
+    assert_eq!(
+        ts_microseconds::serialize(&v, Serializer).unwrap(),
+        json!(1670901134123456i64)
+    );
+    assert_eq!(
+        ts_nanoseconds::serialize(&v, Serializer).unwrap(),
+        json!(1670901134123456789i64)
+    );
+    assert_eq!(
+        ts_seconds::deserialize(json!(1670901134)).unwrap(),
+        datetime("2022-12-13T03:12:14Z")
+    );
+    assert_eq!(
+        ts_milliseconds::deserialize(json!(1670901134123i64)).unwrap(),
+        datetime("2022-12-13T03:12:14.123Z")
+    );
+    assert_eq!(
+        ts_microseconds::deserialize(json!(-1)).unwrap(),
+        datetime("1969-12-31T23:59:59.999999Z")
+    );
+    assert_eq!(
+        ts_nanoseconds::deserialize(json!(1670901134123456789i64)).unwrap(),
+        v
+    );
+    // truncated to the unit
+    let v = datetime("1969-12-31T23:59:59.5Z");
+    assert_eq!(ts_seconds::serialize(&v, Serializer).unwrap(), json!(-1));
+    assert_eq!(
+        ts_milliseconds::serialize(&v, Serializer).unwrap(),
+        json!(-500)
+    );
+    assert!(ts_nanoseconds::serialize(&datetime("2263-01-01T00:00:00Z"), Serializer).is_err());
+    assert!(ts_seconds::deserialize(json!(i64::MAX)).is_err());
+    assert!(ts_seconds::deserialize(json!("1670901134")).is_err());
+}
+
+#[test]
+fn test_option() {
+    let v = Some(datetime("2022-12-13T03:12:14Z"));
+    assert_eq!(
+        ts_seconds_option::serialize(&v, Serializer).unwrap(),
+        json!(1670901134)
+    );
+    assert_eq!(
+        ts_seconds_option::serialize(&None, Serializer).unwrap(),
+        json!(null)
+    );
+    assert_eq!(
+        ts_seconds_option::deserialize(json!(1670901134)).unwrap(),
+        v
+    );
+    assert_eq!(ts_seconds_option::deserialize(json!(null)).unwrap(), None);
+    assert_eq!(
+        ts_nanoseconds_option::deserialize(json!(null)).unwrap(),
+        None
+    );
+    assert_eq!(flexible_option::deserialize(json!(1670901134)).unwrap(), v);
+    assert_eq!(
+        date_days_option::serialize(&None, Serializer).unwrap(),
+        json!(null)
+    );
+    assert_eq!(
+        time_seconds_option::deserialize(json!(60)).unwrap(),
+        Some(Time::from_str("00:01:00").unwrap())
+    );
+}
+
+#[test]
+fn test_flexible() {
+    let v = datetime("2022-12-13T11:12:14+08:00");
+    assert_eq!(
+        flexible::serialize(&v, Serializer).unwrap(),
+        json!("2022-12-13T11:12:14+08:00")
+    );
+    assert_eq!(
+        flexible::deserialize(json!("2022-12-13T11:12:14+08:00")).unwrap(),
+        v
+    );
+    assert_eq!(flexible::deserialize(json!(1670901134)).unwrap(), v);
+    assert_eq!(flexible::deserialize(json!("1670901134")).unwrap(), v);
+    assert_eq!(
+        flexible::deserialize(json!(1670901134.25)).unwrap(),
+        datetime("2022-12-13T03:12:14.25Z")
+    );
+    assert!(flexible::deserialize(json!("yesterday")).is_err());
+    assert!(flexible::deserialize(json!(true)).is_err());
+    assert!(flexible::deserialize(json!(1e300)).is_err());
+}
+
+#[test]
+fn test_date_time() {
+    let date = Date::from_str("2022-12-13").unwrap();
+    assert_eq!(
+        date_days::serialize(&date, Serializer).unwrap(),
+        json!(19339)
+    );
+    assert_eq!(date_days::deserialize(json!(19339)).unwrap(), date);
+    assert_eq!(
+        date_days::deserialize(json!(-1)).unwrap().to_string(),
+        "1969-12-31"
+    );
+    assert!(date_days::deserialize(json!(i64::MAX)).is_err());
+
+    let time = Time::from_str("11:12:14.5").unwrap();
+    assert_eq!(
+        time_seconds::serialize(&time, Serializer).unwrap(),
+        json!(40334)
+    );
+    assert_eq!(
+        time_seconds::deserialize(json!(40334)).unwrap(),
+        Time::from_str("11:12:14").unwrap()
+    );
+    assert!(time_seconds::deserialize(json!(86400)).is_err());
+    assert!(time_seconds::deserialize(json!(-1)).is_err());
+}
+
+fastdate::serde_format!(mod legacy = Format::new("YYYY/MM/DD hh:mm:ss")
+    .with_inputs(&["YYYY/MM/DD hh:mm:ss", "YYYY-MM-DD hh:mm:ss.000000", "YYYY-MM-DDThh:mm:ss+00:00"])
+    .with_offset(8 * 3600));
+
+fastdate::serde_format!(mod utc = Format::new("YYYY-MM-DD hh:mm:ssZ"));
+
+#[test]
+fn test_format() {
+    let v = legacy::deserialize(json!("2022/12/13 11:12:14")).unwrap();
+    assert_eq!(v, datetime("2022-12-13T11:12:14+08:00"));
+    assert_eq!(
+        legacy::deserialize(json!("2022-12-13 11:12:14.500000")).unwrap(),
+        datetime("2022-12-13T11:12:14.5+08:00")
+    );
+    // an offset in the text wins
+    assert_eq!(
+        legacy::deserialize(json!("2022-12-13T03:12:14+00:00")).unwrap(),
+        datetime("2022-12-13T03:12:14Z")
+    );
+    assert!(legacy::deserialize(json!("2022.12.13 11:12:14")).is_err());
+    assert!(legacy::deserialize(json!("2022/12/13 11:12")).is_err());
+    assert!(legacy::deserialize(json!("2022/12/13 11:12:14 ")).is_err());
+    assert!(legacy::deserialize(json!("2022/13/13 11:12:14")).is_err());
+    // written at the offset
+    assert_eq!(
+        legacy::serialize(&datetime("2022-12-13T03:12:14Z"), Serializer).unwrap(),
+        json!("2022/12/13 11:12:14")
+    );
+    assert_eq!(
+        legacy::option::serialize(&Some(v.clone()), Serializer).unwrap(),
+        json!("2022/12/13 11:12:14")
+    );
+    assert_eq!(
+        legacy::option::serialize(&None, Serializer).unwrap(),
+        json!(null)
+    );
+    assert_eq!(
+        legacy::option::deserialize(json!("2022/12/13 11:12:14")).unwrap(),
+        Some(v)
+    );
+    assert_eq!(legacy::option::deserialize(json!(null)).unwrap(), None);
+
+    let v = datetime("2022-12-13T11:12:14+08:00");
+    assert_eq!(
+        utc::serialize(&v.clone().set_offset(0), Serializer).unwrap(),
+        json!("2022-12-13 03:12:14Z")
+    );
+    assert_eq!(utc::deserialize(json!("2022-12-13 03:12:14Z")).unwrap(), v);
+    assert!(utc::deserialize(json!("2022-12-13 03:12:14")).is_err());
+
+    let format = Format::new("DD.MM.YYYY hh:mm").with_offset(-3600);
+    assert_eq!(
+        format.parse("13.12.2022 11:12").unwrap().to_string(),
+        "2022-12-13T11:12:00-01:00"
+    );
+    assert_eq!(format.format(&v), "13.12.2022 02:12");
+}
+
+#[test]
+fn test_deserialize_borrowed() {
+    use serde::Deserialize;
+    use serde::de::value::{BorrowedBytesDeserializer, BorrowedStrDeserializer, Error};
+    let v = DateTime::deserialize(BorrowedStrDeserializer::<Error>::new(
+        "2022-12-13T11:12:14+08:00",
+    ))
+    .unwrap();
+    assert_eq!(v, datetime("2022-12-13T11:12:14+08:00"));
+    let v = DateTime::deserialize(BorrowedBytesDeserializer::<Error>::new(
+        b"2022-12-13T11:12:14+08:00",
+    ))
+    .unwrap();
+    assert_eq!(v, datetime("2022-12-13T11:12:14+08:00"));
+    assert!(DateTime::deserialize(BorrowedBytesDeserializer::<Error>::new(b"\xff")).is_err());
+    let v = Date::deserialize(BorrowedBytesDeserializer::<Error>::new(b"2022-12-13")).unwrap();
+    assert_eq!(v, Date::from_str("2022-12-13").unwrap());
+    let v = Time::deserialize(BorrowedStrDeserializer::<Error>::new("11:12:14")).unwrap();
+    assert_eq!(v, Time::from_str("11:12:14").unwrap());
+
+    // strict by default
+    assert_eq!(
+        serde_json::from_slice::<DateTime>(b"\"2022-12-13T11:12:14+08:00\"").unwrap(),
+        datetime("2022-12-13T11:12:14+08:00")
+    );
+    assert!(serde_json::from_str::<DateTime>("1670901134").is_err());
+    assert!(serde_json::from_str::<DateTime>(r#"{"secs": 1}"#).is_err());
+    assert!(serde_json::from_str::<Date>("19339").is_err());
+    assert!(serde_json::from_str::<Time>("60").is_err());
+    assert!(serde_json::from_value::<Time>(json!("11:12:14")).is_ok());
+}
+
+#[test]
+fn test_lenient() {
+    let v: Lenient<DateTime> = serde_json::from_str("1670901134").unwrap();
+    assert_eq!(v.0, datetime("2022-12-13T03:12:14Z"));
+    let v: Lenient<DateTime> = serde_json::from_str("\"1670901134\"").unwrap();
+    assert_eq!(v.0, datetime("2022-12-13T03:12:14Z"));
+    let v: Lenient<DateTime> = serde_json::from_str("-0.5").unwrap();
+    assert_eq!(v.0, datetime("1969-12-31T23:59:59.5Z"));
+    let v: Lenient<DateTime> =
+        serde_json::from_str(r#"{"secs": 1670901134, "nanos": 123}"#).unwrap();
+    assert_eq!(v.into_inner(), datetime("2022-12-13T03:12:14.000000123Z"));
+    let v: Lenient<DateTime> = serde_json::from_str("\"2022-12-13T11:12:14+08:00\"").unwrap();
+    assert_eq!(
+        serde_json::to_string(&v).unwrap(),
+        "\"2022-12-13T11:12:14+08:00\""
+    );
+    assert!(serde_json::from_str::<Lenient<DateTime>>(r#"{"nanos": 1}"#).is_err());
+    let e = serde_json::from_str::<Lenient<DateTime>>(r#"{"secs": 1, "x": 1}"#).unwrap_err();
+    assert!(e.to_string().starts_with("unknown field `x`"));
+    let v: Lenient<DateTime> = serde_json::from_str(r#"{"nanos": 5, "secs": 1}"#).unwrap();
+    assert_eq!(v.0.unix_timestamp_nano(), 1_000_000_005);
+    assert!(
+        serde_json::from_str::<Lenient<DateTime>>(r#"{"secs": 1, "nanos": 1000000000}"#).is_err()
+    );
+    assert!(serde_json::from_str::<Lenient<DateTime>>("true").is_err());
+
+    let v: Lenient<Date> = serde_json::from_str("19339").unwrap();
+    assert_eq!(v.0, Date::from_str("2022-12-13").unwrap());
+    let v: Lenient<Date> = serde_json::from_str("\"2022-12-13\"").unwrap();
+    assert_eq!(v.0, Date::from_str("2022-12-13").unwrap());
+    assert!(serde_json::from_str::<Lenient<Date>>("1.5").is_err());
+
+    let v: Lenient<Time> = serde_json::from_str("40334").unwrap();
+    assert_eq!(v.0, Time::from_str("11:12:14").unwrap());
+    let v: Lenient<Time> = serde_json::from_str("40334.25").unwrap();
+    assert_eq!(v.0, Time::from_str("11:12:14.25").unwrap());
+    let v: Lenient<Time> = serde_json::from_str(r#"{"secs": 40334, "nanos": 5}"#).unwrap();
+    assert_eq!(v.0, Time::from_str("11:12:14.000000005").unwrap());
+    assert!(serde_json::from_str::<Lenient<Time>>("86400").is_err());
+    assert!(serde_json::from_str::<Lenient<Time>>("-1").is_err());
+
+    let v: Option<Lenient<Date>> = serde_json::from_str("null").unwrap();
+    assert_eq!(v, None);
+}
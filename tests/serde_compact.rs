@@ -0,0 +1,335 @@
+//! the compact forms, through a minimal little-endian format that is not human readable
+use fastdate::serde::{Lenient, flexible};
+use fastdate::{Date, DateTime, Time};
+use serde::de::value::Error;
+use serde::de::{self, DeserializeSeed, SeqAccess, Visitor};
+use serde::ser::{self, Impossible, SerializeTuple};
+use serde::{Deserialize, Serialize, forward_to_deserialize_any};
+use std::str::FromStr;
+
+struct Writer(Vec<u8>);
+
+fn unsupported<T>() -> Result<T, Error> {
+    Err(ser::Error::custom("unsupported"))
+}
+
+impl ser::Serializer for &mut Writer {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Impossible<(), Error>;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = Impossible<(), Error>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        self.serialize_u64(v.len() as u64)?;
+        self.0.extend_from_slice(v.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self, Error> {
+        Ok(self)
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_i8(self, _v: i8) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_i16(self, _v: i16) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_u8(self, _v: u8) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_u16(self, _v: u16) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_f32(self, _v: f32) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_f64(self, _v: f64) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_char(self, _v: char) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_bytes(self, _v: &[u8]) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_none(self) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, _v: &T) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_unit(self) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), Error> {
+        unsupported()
+    }
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        unsupported()
+    }
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        unsupported()
+    }
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        unsupported()
+    }
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        unsupported()
+    }
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct, Error> {
+        unsupported()
+    }
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        unsupported()
+    }
+}
+
+impl SerializeTuple for &mut Writer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, v: &T) -> Result<(), Error> {
+        v.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+struct Reader<'de>(&'de [u8]);
+
+impl<'de> Reader<'de> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        if self.0.len() < N {
+            return Err(de::Error::custom("eof"));
+        }
+        let (v, rest) = self.0.split_at(N);
+        self.0 = rest;
+        Ok(v.try_into().unwrap())
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Reader<'de> {
+    type Error = Error;
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+        Err(de::Error::custom("unsupported"))
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_i32(i32::from_le_bytes(self.take()?))
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_i64(i64::from_le_bytes(self.take()?))
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_u32(u32::from_le_bytes(self.take()?))
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_u64(u64::from_le_bytes(self.take()?))
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = u64::from_le_bytes(self.take()?) as usize;
+        if self.0.len() < len {
+            return Err(de::Error::custom("eof"));
+        }
+        let (v, rest) = self.0.split_at(len);
+        self.0 = rest;
+        visitor.visit_borrowed_str(std::str::from_utf8(v).map_err(de::Error::custom)?)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(Elements { de: self, len })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i128 u8 u16 u128 f32 f64 char string bytes byte_buf option unit
+        unit_struct newtype_struct seq tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct Elements<'a, 'de> {
+    de: &'a mut Reader<'de>,
+    len: usize,
+}
+
+impl<'de> SeqAccess<'de> for Elements<'_, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+}
+
+fn to_bytes<T: Serialize>(v: &T) -> Vec<u8> {
+    let mut w = Writer(vec![]);
+    v.serialize(&mut w).unwrap();
+    w.0
+}
+
+fn from_bytes<'de, T: Deserialize<'de>>(v: &'de [u8]) -> Result<T, Error> {
+    let mut r = Reader(v);
+    let v = T::deserialize(&mut r)?;
+    assert!(r.0.is_empty());
+    Ok(v)
+}
+
+#[test]
+fn test_datetime() {
+    let v = DateTime::from_str("2022-12-13T11:12:14.123456789+08:00").unwrap();
+    let bytes = to_bytes(&v);
+    let mut expect = 1670901134i64.to_le_bytes().to_vec();
+    expect.extend_from_slice(&123456789u32.to_le_bytes());
+    expect.extend_from_slice(&28800i32.to_le_bytes());
+    assert_eq!(bytes, expect);
+    let back: DateTime = from_bytes(&bytes).unwrap();
+    assert_eq!(back.to_string(), v.to_string());
+
+    let v = DateTime::from_str("1969-12-31T23:59:59.5-05:30").unwrap();
+    let back: DateTime = from_bytes(&to_bytes(&v)).unwrap();
+    assert_eq!(back.to_string(), "1969-12-31T23:59:59.5-05:30");
+
+    let v = DateTime::from_str("2016-12-31T23:59:60.25Z").unwrap();
+    let back: DateTime = from_bytes(&to_bytes(&v)).unwrap();
+    assert!(back.is_leap_second());
+    assert_eq!(back.to_string(), "2016-12-31T23:59:60.25Z");
+
+    // a leap second not at 23:59:59 UTC
+    let mut bytes = 0i64.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&1_000_000_000u32.to_le_bytes());
+    bytes.extend_from_slice(&0i32.to_le_bytes());
+    assert!(from_bytes::<DateTime>(&bytes).is_err());
+    // an offset out of range
+    let mut bytes = 0i64.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&0u32.to_le_bytes());
+    bytes.extend_from_slice(&100_000i32.to_le_bytes());
+    assert!(from_bytes::<DateTime>(&bytes).is_err());
+}
+
+#[test]
+fn test_date() {
+    let v = Date::from_str("2022-12-13").unwrap();
+    assert_eq!(to_bytes(&v), 19339i32.to_le_bytes());
+    assert_eq!(from_bytes::<Date>(&to_bytes(&v)).unwrap(), v);
+    for v in [Date::MIN, Date::MAX] {
+        assert_eq!(from_bytes::<Date>(&to_bytes(&v)).unwrap(), v);
+    }
+    assert!(from_bytes::<Date>(&i32::MAX.to_le_bytes()).is_err());
+}
+
+#[test]
+fn test_time() {
+    let v = Time::from_str("11:12:14.123456789").unwrap();
+    assert_eq!(to_bytes(&v), 40334123456789u64.to_le_bytes());
+    assert_eq!(from_bytes::<Time>(&to_bytes(&v)).unwrap(), v);
+    let v = Time::from_str("23:59:60.5").unwrap();
+    let back: Time = from_bytes(&to_bytes(&v)).unwrap();
+    assert!(back.is_leap_second());
+    assert_eq!(back, v);
+    // 10:00:60 and 10:01:00 are different
+    let a = Time::from_str("10:00:60").unwrap();
+    let b = Time::from_str("10:01:00").unwrap();
+    assert_ne!(to_bytes(&a), to_bytes(&b));
+    assert_eq!(from_bytes::<Time>(&to_bytes(&a)).unwrap(), a);
+    assert!(from_bytes::<Time>(&u64::MAX.to_le_bytes()).is_err());
+}
+
+#[test]
+fn test_lenient_flexible() {
+    let v = DateTime::from_str("2022-12-13T11:12:14+08:00").unwrap();
+    let back: Lenient<DateTime> = from_bytes(&to_bytes(&v)).unwrap();
+    assert_eq!(back.0, v);
+    let d = Date::from_str("2022-12-13").unwrap();
+    assert_eq!(from_bytes::<Lenient<Date>>(&to_bytes(&d)).unwrap().0, d);
+    let t = Time::from_str("11:12:14").unwrap();
+    assert_eq!(from_bytes::<Lenient<Time>>(&to_bytes(&t)).unwrap().0, t);
+
+    let mut w = Writer(vec![]);
+    flexible::serialize(&v, &mut w).unwrap();
+    let back = flexible::deserialize(&mut Reader(&w.0)).unwrap();
+    assert_eq!(back, v);
+}
@@ -77,23 +77,13 @@ fn test_set_hour() {
 
 #[test]
 fn test_display() {
-    let d = Time {
-        nano: 0,
-        sec: 0,
-        minute: 0,
-        hour: 8,
-    };
+    let d = Time::new(8, 0, 0, 0).unwrap();
     assert_eq!("08:00:00", d.to_string());
 }
 
 #[test]
 fn test_display2() {
-    let d = Time {
-        nano: 123456,
-        sec: 0,
-        minute: 0,
-        hour: 8,
-    };
+    let d = Time::new(8, 0, 0, 123456).unwrap();
     assert_eq!("08:00:00.000123456", format!("{}", d));
 }
 
@@ -162,31 +152,21 @@ fn test_date_12() {
 #[test]
 fn test_from_micros() {
     let d = Duration::from_micros(3 * 60 * 60 * 1000000 + 1);
-    let t = Time::from(d);
+    let t = Time::try_from(d).unwrap();
     println!("{}", t);
     assert_eq!(t.to_string(), "03:00:00.000001");
 }
 
 #[test]
 fn test_from_time() {
-    let d = Duration::from(Time {
-        nano: 1000,
-        sec: 1,
-        minute: 1,
-        hour: 1,
-    });
+    let d = Duration::from(Time::new(1, 1, 1, 1000).unwrap());
     println!("{}", d.as_nanos());
     assert_eq!(d.as_nanos(), 3661000001000);
 }
 
 #[test]
 fn test_display_time_nano_zero() {
-    let d = Time {
-        nano: 0,
-        sec: 0,
-        minute: 0,
-        hour: 0,
-    };
+    let d = Time::new(0, 0, 0, 0).unwrap();
     let mut buf: [u8; 18] = *b"00:00:00.000000000";
     let len = d.display_time(0, &mut buf);
     assert_eq!(len, 8);
@@ -194,12 +174,7 @@ fn test_display_time_nano_zero() {
 
 #[test]
 fn test_display_time_nano_zero_no() {
-    let d = Time {
-        nano: 1,
-        sec: 0,
-        minute: 0,
-        hour: 0,
-    };
+    let d = Time::new(0, 0, 0, 1).unwrap();
     let mut buf: [u8; 18] = *b"00:00:00.000000000";
     let len = d.display_time(0, &mut buf);
     println!("{}", std::str::from_utf8(&buf).unwrap());
@@ -220,3 +195,306 @@ fn test_get_micro() {
     let date = Time::from_str("14:01:58.175861").unwrap();
     assert_eq!(175861, date.get_micro());
 }
+
+#[test]
+fn test_new() {
+    assert_eq!(
+        Time::new(23, 59, 59, 999_999_999).unwrap().to_string(),
+        "23:59:59.999999999"
+    );
+    assert_eq!(
+        Time::new(24, 0, 0, 0).err().unwrap().to_string(),
+        "OutOfRangeHour"
+    );
+    assert_eq!(
+        Time::new(0, 60, 0, 0).err().unwrap().to_string(),
+        "OutOfRangeMinute"
+    );
+    assert_eq!(
+        Time::new(0, 0, 60, 0).err().unwrap().to_string(),
+        "OutOfRangeSecond"
+    );
+    assert_eq!(
+        Time::new(0, 0, 0, 1_000_000_000).err().unwrap().to_string(),
+        "OutOfRangeNano"
+    );
+}
+
+#[test]
+fn test_is_valid() {
+    assert!(Time::new(99, 0, 0, 0).is_err());
+    assert!(Time::new(1, 2, 3, 4).unwrap().is_valid());
+}
+
+#[test]
+fn test_with() {
+    let t = Time::new(1, 2, 3, 4).unwrap();
+    assert_eq!(t.clone().with_hour(23).unwrap().get_hour(), 23);
+    assert!(t.clone().with_hour(99).is_err());
+    assert!(t.clone().with_minute(60).is_err());
+    assert!(t.clone().with_sec(60).is_err());
+    let leap = Time::from_str("23:59:60.25").unwrap();
+    assert_eq!(leap.clone().with_sec(0).unwrap().to_string(), "23:59:00.25");
+    assert!(!leap.with_sec(59).unwrap().is_leap_second());
+    assert!(t.clone().with_nano(4_000_000_000).is_err());
+    assert!(t.clone().with_micro(1_000_000).is_err());
+    assert_eq!(t.clone().with_micro(5).unwrap().get_nano(), 5000);
+}
+
+#[test]
+fn test_set_out_of_range() {
+    let t = Time::from_str("01:00:00").unwrap();
+    assert_eq!(t.clone().set_hour(99), t);
+    assert_eq!(t.clone().set_minute(60), t);
+    assert_eq!(t.clone().set_sec(60), t);
+    assert_eq!(t.clone().set_nano(1_000_000_000), t);
+    assert_eq!(t.clone().set_micro(1_000_000), t);
+}
+
+#[test]
+fn test_try_from_duration() {
+    let t = Time::try_from(Duration::from_secs(24 * 3600 - 1)).unwrap();
+    assert_eq!(t.to_string(), "23:59:59");
+    assert!(Time::try_from(Duration::from_secs(24 * 3600)).is_err());
+    assert!(Time::try_from(Duration::from_secs(300 * 3600)).is_err());
+    let t = Time::try_from(Duration::from_nanos(3_723_000_000_004)).unwrap();
+    assert_eq!(t, Time::new(1, 2, 3, 4).unwrap());
+    assert_eq!(Duration::from(t), Duration::from_nanos(3_723_000_000_004));
+}
+
+#[test]
+fn test_ord() {
+    let a = Time::from_str("11:12:13.5").unwrap();
+    let b = Time::from_str("11:12:14").unwrap();
+    let c = Time::from_str("02:59:59.999").unwrap();
+    assert!(a < b);
+    assert!(c < a);
+    let mut v = vec![b.clone(), a.clone(), c.clone()];
+    v.sort();
+    assert_eq!(v, vec![c, a, b]);
+}
+
+#[test]
+fn test_overflowing_add() {
+    let t = Time::from_str("23:00:00").unwrap();
+    let (v, days) = t.overflowing_add(Duration::from_secs(3600));
+    assert_eq!((v.to_string(), days), ("00:00:00".to_string(), 1));
+    let (v, days) = t.overflowing_add(Duration::from_secs(59 * 3600 + 1));
+    assert_eq!((v.to_string(), days), ("10:00:01".to_string(), 3));
+    let (v, days) = t.overflowing_add(Duration::from_nanos(1));
+    assert_eq!((v.to_string(), days), ("23:00:00.000000001".to_string(), 0));
+    let (_, days) = t.overflowing_add(Duration::MAX);
+    assert_eq!(
+        days as u128,
+        (Duration::MAX.as_secs() as u128 + 23 * 3600) / 86400
+    );
+}
+
+#[test]
+fn test_overflowing_sub() {
+    let t = Time::from_str("01:00:00").unwrap();
+    let (v, days) = t.overflowing_sub(Duration::from_secs(3600));
+    assert_eq!((v.to_string(), days), ("00:00:00".to_string(), 0));
+    let (v, days) = t.overflowing_sub(Duration::from_secs(3601));
+    assert_eq!((v.to_string(), days), ("23:59:59".to_string(), 1));
+    let (v, days) = t.overflowing_sub(Duration::from_secs(49 * 3600));
+    assert_eq!((v.to_string(), days), ("00:00:00".to_string(), 2));
+    let (v, days) = t.overflowing_sub(Duration::from_secs(49 * 3600 + 1));
+    assert_eq!((v.to_string(), days), ("23:59:59".to_string(), 3));
+}
+
+#[test]
+fn test_wrapping() {
+    let t = Time::from_str("22:30:00").unwrap();
+    assert_eq!(
+        t.wrapping_add(Duration::from_secs(2 * 3600)).to_string(),
+        "00:30:00"
+    );
+    assert_eq!(
+        t.wrapping_sub(Duration::from_secs(23 * 3600)).to_string(),
+        "23:30:00"
+    );
+}
+
+#[test]
+fn test_sub_time() {
+    let a = Time::from_str("22:30:00").unwrap();
+    let b = Time::from_str("01:00:00.5").unwrap();
+    assert_eq!(
+        a.clone() - b.clone(),
+        fastdate::time1::Duration::nanoseconds(77_399_500_000_000)
+    );
+    assert_eq!(
+        b - a,
+        fastdate::time1::Duration::nanoseconds(-77_399_500_000_000)
+    );
+}
+
+#[test]
+fn test_from_str_hour_minute() {
+    let t = Time::from_str("14:30").unwrap();
+    assert_eq!(t, Time::new(14, 30, 0, 0).unwrap());
+    assert!(Time::from_str("14:3").is_err());
+    assert!(Time::from_str("24:30").is_err());
+    assert!(Time::from_str("14:30xyz").is_err());
+    assert!(Time::from_str("14:30:15.5 ").is_err());
+    assert!(Time::from_str("1430xyz").is_err());
+}
+
+#[test]
+fn test_from_str_basic() {
+    assert_eq!(
+        Time::from_str("143000").unwrap(),
+        Time::new(14, 30, 0, 0).unwrap()
+    );
+    assert_eq!(
+        Time::from_str("1430").unwrap(),
+        Time::new(14, 30, 0, 0).unwrap()
+    );
+    assert_eq!(
+        Time::from_str("143015.25").unwrap(),
+        Time::new(14, 30, 15, 250_000_000).unwrap()
+    );
+    assert!(Time::from_str("146000").is_err());
+    assert!(Time::from_str("14300").is_err());
+    assert!(Time::from_str("2022-12-13").is_err());
+}
+
+#[test]
+fn test_from_str_clock12() {
+    let pm = Time::new(14, 30, 0, 0).unwrap();
+    for s in [
+        "2:30 PM",
+        "2:30PM",
+        "02:30 pm",
+        "2:30 p.m.",
+        "2:30 P.M",
+        "2:30 Pm",
+    ] {
+        assert_eq!(Time::from_str(s).unwrap(), pm, "{}", s);
+    }
+    assert_eq!(
+        Time::from_str("2:30:15.5 a.m.").unwrap(),
+        Time::new(2, 30, 15, 500_000_000).unwrap()
+    );
+    assert_eq!(
+        Time::from_str("12:05 AM").unwrap(),
+        Time::new(0, 5, 0, 0).unwrap()
+    );
+    assert_eq!(
+        Time::from_str("12:05 PM").unwrap(),
+        Time::new(12, 5, 0, 0).unwrap()
+    );
+    assert_eq!(
+        Time::from_str("7 pm").unwrap(),
+        Time::new(19, 0, 0, 0).unwrap()
+    );
+    assert!(Time::from_str("13:00 PM").is_err());
+    assert!(Time::from_str("0:30 AM").is_err());
+    assert!(Time::from_str("2:30x PM").is_err());
+    assert!(Time::from_str("PM").is_err());
+}
+
+#[test]
+fn test_parse_end_of_day() {
+    for s in ["24:00", "24:00:00", "24:00:00.000", "2400", "240000"] {
+        let (t, next_day) = Time::parse_end_of_day(s).unwrap();
+        assert_eq!(t, Time::new(0, 0, 0, 0).unwrap(), "{}", s);
+        assert!(next_day);
+    }
+    assert!(Time::parse_end_of_day("24:00:01").is_err());
+    assert!(Time::parse_end_of_day("24:30").is_err());
+    assert!(Time::from_str("24:00").is_err());
+    let (t, next_day) = Time::parse_end_of_day("23:59").unwrap();
+    assert_eq!(t, Time::new(23, 59, 0, 0).unwrap());
+    assert!(!next_day);
+}
+
+#[test]
+fn test_display_style() {
+    use fastdate::TimeStyle;
+    let t = Time::new(0, 5, 9, 500_000_000).unwrap();
+    assert_eq!(t.display_style(TimeStyle::Extended), "00:05:09.5");
+    assert_eq!(t.display_style(TimeStyle::HourMinute), "00:05");
+    assert_eq!(t.display_style(TimeStyle::Basic), "000509.5");
+    assert_eq!(t.display_style(TimeStyle::Clock12), "12:05:09.5 AM");
+    let t = Time::new(12, 0, 0, 0).unwrap();
+    assert_eq!(t.display_style(TimeStyle::Clock12), "12:00 PM");
+    let t = Time::new(23, 59, 0, 0).unwrap();
+    assert_eq!(t.display_style(TimeStyle::Clock12), "11:59 PM");
+    for style in [TimeStyle::Extended, TimeStyle::Basic, TimeStyle::Clock12] {
+        let t = Time::new(17, 45, 30, 123).unwrap();
+        assert_eq!(Time::from_str(&t.display_style(style)).unwrap(), t);
+    }
+}
+
+#[test]
+fn test_leap_second() {
+    use fastdate::LeapSecond;
+    let t = Time::from_str("23:59:60.5").unwrap();
+    assert!(t.is_leap_second());
+    assert_eq!(t.get_sec(), 59);
+    assert_eq!(t.get_nano(), 1_500_000_000);
+    assert_eq!(t.to_string(), "23:59:60.5");
+    assert_eq!(Time::new(23, 59, 59, 1_500_000_000).unwrap(), t);
+    assert!(Time::new(23, 59, 59, 999_999_999).unwrap() < t);
+    assert!(Time::new(0, 0, 0, 1_000_000_000).is_err());
+    assert!(Time::from_str("23:59:61").is_err());
+    let t = Time::from_str_leap("23:59:60.5", LeapSecond::Clamp).unwrap();
+    assert_eq!(t.to_string(), "23:59:59.999999999");
+    assert!(!t.is_leap_second());
+    assert!(Time::from_str_leap("23:59:60", LeapSecond::Reject).is_err());
+    assert!(Time::from_str_leap("23:59:59", LeapSecond::Reject).is_ok());
+}
+
+#[test]
+fn test_truncate_round_ceil() {
+    use fastdate::{RoundMode, TimeUnit};
+    let t = Time::from_str("11:12:14.5").unwrap();
+    assert_eq!(t.truncate(TimeUnit::Nano), t);
+    assert_eq!(t.truncate(TimeUnit::Second).to_string(), "11:12:14");
+    assert_eq!(t.truncate(TimeUnit::Hour).to_string(), "11:00:00");
+    assert_eq!(t.truncate(TimeUnit::Day).to_string(), "00:00:00");
+    assert_eq!(t.ceil(TimeUnit::Minute).to_string(), "11:13:00");
+    assert_eq!(t.ceil(TimeUnit::Milli), t);
+    assert_eq!(
+        t.round(TimeUnit::Second, RoundMode::HalfUp).to_string(),
+        "11:12:15"
+    );
+    assert_eq!(
+        t.round(TimeUnit::Second, RoundMode::HalfDown).to_string(),
+        "11:12:14"
+    );
+    assert_eq!(
+        t.round(TimeUnit::Second, RoundMode::HalfEven).to_string(),
+        "11:12:14"
+    );
+    let t = Time::from_str("11:12:15.5").unwrap();
+    assert_eq!(
+        t.round(TimeUnit::Second, RoundMode::HalfEven).to_string(),
+        "11:12:16"
+    );
+    let t = Time::from_str("23:30").unwrap();
+    assert_eq!(t.ceil(TimeUnit::Hour).to_string(), "00:00:00");
+    let t = Time::from_str("23:59:60.5").unwrap();
+    assert_eq!(t.truncate(TimeUnit::Second).to_string(), "23:59:59");
+}
+
+#[test]
+fn test_truncate_every() {
+    use fastdate::{RoundMode, TimeUnit};
+    let t = Time::from_str("11:12:14").unwrap();
+    let unit = TimeUnit::every(Duration::from_secs(5 * 60));
+    assert_eq!(t.truncate(unit).to_string(), "11:10:00");
+    assert_eq!(t.ceil(unit).to_string(), "11:15:00");
+    assert_eq!(t.round(unit, RoundMode::HalfUp).to_string(), "11:10:00");
+    let unit = TimeUnit::Every {
+        step: Duration::from_secs(15 * 60),
+        origin: Duration::from_secs(5 * 60),
+    };
+    assert_eq!(t.truncate(unit).to_string(), "11:05:00");
+    assert_eq!(t.ceil(unit).to_string(), "11:20:00");
+    let t = Time::from_str("00:02").unwrap();
+    assert_eq!(t.truncate(unit).to_string(), "23:50:00");
+    assert_eq!(t.truncate(TimeUnit::every(Duration::ZERO)), t);
+}
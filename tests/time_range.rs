@@ -0,0 +1,112 @@
+use fastdate::{DateTime, Time, TimeRange};
+use std::str::FromStr;
+use std::time::Duration;
+
+fn range(s: &str) -> TimeRange {
+    TimeRange::from_str(s).unwrap()
+}
+
+fn time(s: &str) -> Time {
+    Time::from_str(s).unwrap()
+}
+
+#[test]
+fn test_from_str() {
+    let v = range("22:00-06:00");
+    assert_eq!(v.start, time("22:00"));
+    assert_eq!(v.end, time("06:00"));
+    assert_eq!(v.to_string(), "22:00-06:00");
+    assert_eq!(range("09:00 - 17:30").to_string(), "09:00-17:30");
+    assert_eq!(range("09:00:30-17:30").to_string(), "09:00:30-17:30:00");
+    assert!(TimeRange::from_str("22:00").is_err());
+    assert!(TimeRange::from_str("22:00-25:00").is_err());
+}
+
+#[test]
+fn test_contains() {
+    let v = range("22:00-06:00");
+    assert!(v.crosses_midnight());
+    assert!(v.contains(&time("22:00")));
+    assert!(v.contains(&time("00:00")));
+    assert!(!v.contains(&time("06:00")));
+    assert!(!v.contains(&time("12:00")));
+    let v = range("09:00-17:00");
+    assert!(!v.crosses_midnight());
+    assert!(v.contains(&time("09:00")));
+    assert!(!v.contains(&time("17:00")));
+    assert!(!v.contains(&time("08:59:59.999999999")));
+    let v = range("08:00-08:00");
+    assert!(v.is_whole_day());
+    assert!(v.contains(&time("07:59")));
+    assert!(range("22:00-00:00").contains(&time("23:59:60.5")));
+}
+
+#[test]
+fn test_contains_datetime() {
+    let v = range("22:00-06:00");
+    let dt = DateTime::from_str("2022-12-13T23:00:00+08:00").unwrap();
+    assert!(v.contains_datetime(&dt));
+    assert!(!v.contains_datetime(&dt.set_offset(0)));
+}
+
+#[test]
+fn test_duration() {
+    assert_eq!(
+        range("22:00-06:00").duration(),
+        Duration::from_secs(8 * 3600)
+    );
+    assert_eq!(
+        range("09:00-17:30").duration(),
+        Duration::from_secs(8 * 3600 + 1800)
+    );
+    assert_eq!(
+        range("22:00-00:00").duration(),
+        Duration::from_secs(2 * 3600)
+    );
+    assert_eq!(
+        TimeRange::whole_day().duration(),
+        Duration::from_secs(86400)
+    );
+}
+
+#[test]
+fn test_intersection() {
+    let v = range("22:00-06:00");
+    assert_eq!(
+        v.intersection(&range("05:00-23:00")),
+        vec![range("05:00-06:00"), range("22:00-23:00")]
+    );
+    assert_eq!(
+        v.intersection(&range("23:00-01:00")),
+        vec![range("23:00-01:00")]
+    );
+    assert_eq!(v.intersection(&range("12:00-13:00")), vec![]);
+    assert!(!v.overlaps(&range("06:00-22:00")));
+    assert!(v.overlaps(&range("05:59-07:00")));
+    assert_eq!(
+        v.intersection(&TimeRange::whole_day()),
+        vec![range("22:00-06:00")]
+    );
+}
+
+#[test]
+fn test_union() {
+    let v = range("22:00-06:00");
+    assert_eq!(v.union(&range("06:00-08:00")), Some(range("22:00-08:00")));
+    assert_eq!(v.union(&range("20:00-23:00")), Some(range("20:00-06:00")));
+    assert_eq!(v.union(&range("01:00-02:00")), Some(v.clone()));
+    assert_eq!(v.union(&range("12:00-13:00")), None);
+    assert!(v.union(&range("06:00-22:00")).unwrap().is_whole_day());
+    assert_eq!(
+        range("09:00-12:00").union(&range("11:00-17:00")),
+        Some(range("09:00-17:00"))
+    );
+}
+
+#[test]
+fn test_ser_de() {
+    let v = range("22:00-06:00");
+    let js = serde_json::to_string(&v).unwrap();
+    assert_eq!(js, "\"22:00-06:00\"");
+    assert_eq!(serde_json::from_str::<TimeRange>(&js).unwrap(), v);
+}